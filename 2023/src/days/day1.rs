@@ -1,65 +1,70 @@
-use std::cmp::Ordering;
+use aocutils::{named_enum, register_day, Answer, Input, Result, Solution};
 
-fn to_digit(s: &str) -> Option<u32> {
-    match s {
-        "one" => Some(1),
-        "1" => Some(1),
-        "two" => Some(2),
-        "2" => Some(2),
-        "three" => Some(3),
-        "3" => Some(3),
-        "four" => Some(4),
-        "4" => Some(4),
-        "five" => Some(5),
-        "5" => Some(5),
-        "six" => Some(6),
-        "6" => Some(6),
-        "seven" => Some(7),
-        "7" => Some(7),
-        "eight" => Some(8),
-        "8" => Some(8),
-        "nine" => Some(9),
-        "9" => Some(9),
-        _ => None,
+named_enum! {
+    enum Digit {
+        One => "1" | "one",
+        Two => "2" | "two",
+        Three => "3" | "three",
+        Four => "4" | "four",
+        Five => "5" | "five",
+        Six => "6" | "six",
+        Seven => "7" | "seven",
+        Eight => "8" | "eight",
+        Nine => "9" | "nine",
     }
 }
 
-fn compare(first: &&(usize, &str), second: &&(usize, &str)) -> Ordering {
-    first.0.cmp(&second.0)
+impl Digit {
+    fn value(self) -> u32 {
+        self.index() as u32 + 1
+    }
 }
 
-fn extract_number(input: &String) -> Option<u32> {
-    let digits = vec![
-        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "1", "2", "3", "4",
-        "5", "6", "7", "8", "9",
-    ];
-
-    let matches: Vec<_> = digits
-        .iter()
-        .map(|digit| input.match_indices(digit))
-        .flatten()
+fn extract_number(input: &str) -> Option<u32> {
+    let matches: Vec<_> = Digit::ALL
+        .into_iter()
+        .flat_map(|digit| {
+            digit
+                .names()
+                .iter()
+                .flat_map(move |name| input.match_indices(name))
+                .map(move |(index, _)| (index, digit))
+        })
         .collect();
 
-    let first_digit = to_digit(matches.iter().min_by(compare)?.1)?;
-    let second_digit = to_digit(matches.iter().max_by(compare)?.1)?;
+    let (_, first_digit) = matches.iter().min()?;
+    let (_, second_digit) = matches.iter().max()?;
 
-    Some(first_digit * 10 + second_digit)
+    Some(first_digit.value() * 10 + second_digit.value())
 }
 
-pub fn day1(input: Vec<String>) {
-    println!("Welcome to day 1.");
-    let digits: Vec<_> = (&input)
-        .iter()
-        .map(|x| x.chars().filter_map(|c| c.to_digit(10)).collect::<Vec<_>>())
-        .collect();
-    let sum: u32 = digits
-        .iter()
-        .filter_map(|number| Some(number.iter().nth(0)? * 10 + number.iter().nth_back(0)?))
-        .sum();
-    println!("The answer for part one is {sum}");
+pub struct Day1;
+
+register_day!(2023, 1, "Trebuchet?!", Day1);
+
+impl Solution for Day1 {
+    type Parsed<'a> = &'a Input;
 
-    let sum: u32 = input.iter().filter_map(extract_number).sum();
-    println!("The answer for part two is {sum:?}");
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        Ok(input)
+    }
+
+    fn part_one(&self, input: &Self::Parsed<'_>) -> Result<Answer> {
+        let digits: Vec<_> = input
+            .lines()
+            .map(|x| x.chars().filter_map(|c| c.to_digit(10)).collect::<Vec<_>>())
+            .collect();
+        let sum: u32 = digits
+            .iter()
+            .filter_map(|number| Some(number.first()? * 10 + number.last()?))
+            .sum();
+        Ok(sum.into())
+    }
+
+    fn part_two(&self, input: &Self::Parsed<'_>) -> Result<Answer> {
+        let sum: u32 = input.lines().filter_map(extract_number).sum();
+        Ok(sum.into())
+    }
 }
 
 #[cfg(test)]
@@ -68,12 +73,12 @@ mod tests {
 
     #[test]
     fn extract_number_test() {
-        assert_eq!(extract_number(&"two1nine".to_string()), Some(29));
-        assert_eq!(extract_number(&"eightwothree".to_string()), Some(83));
-        assert_eq!(extract_number(&"abcone2threexyz".to_string()), Some(13));
-        assert_eq!(extract_number(&"xtwone3four".to_string()), Some(24));
-        assert_eq!(extract_number(&"4nineeightseven2".to_string()), Some(42));
-        assert_eq!(extract_number(&"zoneight234".to_string()), Some(14));
-        assert_eq!(extract_number(&"7pqrstsixteen".to_string()), Some(76));
+        assert_eq!(extract_number("two1nine"), Some(29));
+        assert_eq!(extract_number("eightwothree"), Some(83));
+        assert_eq!(extract_number("abcone2threexyz"), Some(13));
+        assert_eq!(extract_number("xtwone3four"), Some(24));
+        assert_eq!(extract_number("4nineeightseven2"), Some(42));
+        assert_eq!(extract_number("zoneight234"), Some(14));
+        assert_eq!(extract_number("7pqrstsixteen"), Some(76));
     }
 }
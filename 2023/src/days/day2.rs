@@ -1,148 +1,87 @@
-use regex::Regex;
+use aocutils::{aoc_example, named_enum, register_day, Answer, Input, Line, Result, Solution};
 
-#[derive(Debug)]
-enum Cube {
-    Red(i32),
-    Green(i32),
-    Blue(i32),
-}
-
-impl PartialEq for Cube {
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::Red(_), Self::Red(_)) => true,
-            (Self::Green(_), Self::Green(_)) => true,
-            (Self::Blue(_), Self::Blue(_)) => true,
-            _ => false,
-        }
+named_enum! {
+    enum Cube {
+        Red => "red",
+        Green => "green",
+        Blue => "blue",
     }
 }
 
-fn amount(cube: &Cube) -> i32 {
-    match cube {
-        Cube::Red(a) => *a,
-        Cube::Green(a) => *a,
-        Cube::Blue(a) => *a,
-    }
-}
-
-fn cube_with_new_amount(cube: &Cube, amount: i32) -> Cube {
-    match cube {
-        Cube::Red(_) => Cube::Red(amount),
-        Cube::Green(_) => Cube::Green(amount),
-        Cube::Blue(_) => Cube::Blue(amount),
-    }
-}
-
-fn parse_cube(color: &str, amount: i32) -> Option<Cube> {
-    match color {
-        "red" => Some(Cube::Red(amount)),
-        "green" => Some(Cube::Green(amount)),
-        "blue" => Some(Cube::Blue(amount)),
-        _ => None,
-    }
-}
-
-type Draw = Vec<Cube>;
-
-fn parse_draw(input: &str) -> Option<Draw> {
-    let re_draw = Regex::new(
-        r"(?x)
-          (?<amount>\d+)\s
-          (?<type>[a-z]+)
-          (;|,)?",
-    )
-    .ok()?;
-
-    let mut draw = Draw::new();
-    for draw_capture in re_draw.captures_iter(input) {
-        let amount = draw_capture.name("amount")?.as_str().parse::<i32>().ok()?;
-        let cube_type = draw_capture.name("type")?;
+/// How many cubes of each color were drawn, indexed by [`Cube::index`].
+type Draw = [i32; Cube::ALL.len()];
 
-        draw.push(parse_cube(cube_type.as_str(), amount)?);
+fn parse_draw(draw: Line) -> Result<Draw> {
+    let mut counts = Draw::default();
+    for cube in draw.split(",") {
+        let (amount, color) = cube.split_once(" ")?;
+        counts[color.parse::<Cube>()?.index()] += amount.parse::<i32>()?;
     }
-    Some(draw)
+    Ok(counts)
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: usize,
     draws: Vec<Draw>,
 }
 
-fn parse_game(line: &String) -> Option<Game> {
-    let re_game = Regex::new(r"Game\s(?<id>\d+):").ok()?;
-
-    let id = re_game
-        .captures(line.as_str())?
-        .name("id")?
-        .as_str()
-        .parse::<usize>()
-        .ok()?;
-    let mut draws: Vec<Draw> = Vec::new();
-    for draws_slice in line.split_terminator(";") {
-        draws.push(parse_draw(draws_slice)?);
-    }
-
-    if draws.is_empty() {
-        return None;
-    }
-
-    Some(Game { id, draws })
+fn parse_game(line: Line) -> Result<Game> {
+    let (id, draws) = line.split_once(":")?;
+    let id = id.strip_prefix("Game ")?.parse()?;
+    let draws = draws.split(";").map(parse_draw).collect::<Result<_>>()?;
+    Ok(Game { id, draws })
 }
 
 fn is_draw_possible(maximum_draw: &Draw, draw: &Draw) -> bool {
-    for cube in draw {
-        let position = maximum_draw.iter().position(|c| c == cube);
-        if position.is_none() {
-            return false;
-        }
-        let maximum_amount = amount(&maximum_draw[position.unwrap()]);
-        let cube_amount = amount(&cube);
-        if cube_amount > maximum_amount {
-            return false;
-        }
-    }
-    true
+    draw.iter()
+        .zip(maximum_draw)
+        .all(|(amount, maximum)| amount <= maximum)
 }
 
-fn find_minimum_configuration(game: &Game) -> Option<Draw> {
-    let mut configuration = vec![Cube::Red(0), Cube::Green(0), Cube::Blue(0)];
+fn find_minimum_configuration(game: &Game) -> Draw {
+    let mut configuration = Draw::default();
     for draw in &game.draws {
-        for cube in draw {
-            let index = &configuration.iter().position(|c| c == cube)?;
-            let cube_amount = amount(&cube);
-            if cube_amount > amount(&configuration[index.clone()]) {
-                configuration[index.clone()] =
-                    cube_with_new_amount(&configuration[index.clone()], cube_amount);
-            }
+        for (minimum, amount) in configuration.iter_mut().zip(draw) {
+            *minimum = (*minimum).max(*amount);
         }
     }
-    Some(configuration)
+    configuration
 }
 
-pub fn day2(input: Vec<String>) {
-    let games: Vec<_> = input.iter().filter_map(parse_game).collect();
-    let bag_configutarion = vec![Cube::Red(12), Cube::Green(13), Cube::Blue(14)];
-    let possible_games: Vec<_> = games
-        .iter()
-        .filter(|game| {
-            game.draws
-                .iter()
-                .all(|draw| is_draw_possible(&bag_configutarion, draw))
-        })
-        .collect();
+#[aoc_example(file = "inputs/day-2-test.txt", part_one = 8, part_two = 2286)]
+pub struct Day2;
+
+register_day!(2023, 2, "Cube Conundrum", Day2);
 
-    let id_sum: usize = possible_games.iter().map(|game| game.id).sum();
-    println!("The sum of possible game IDs is {id_sum}");
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Game>;
+
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        input.parse_lines(parse_game)
+    }
 
-    let configurations: Vec<_> = games
-        .iter()
-        .filter_map(find_minimum_configuration)
-        .collect();
-    let power_sum: i32 = configurations
-        .iter()
-        .map(|c| c.iter().map(|cube| amount(cube)).product::<i32>())
-        .sum();
-    println!("The sum of powers is {power_sum}");
+    fn part_one(&self, games: &Self::Parsed<'_>) -> Result<Answer> {
+        // 12 red, 13 green and 14 blue cubes.
+        let bag_configuration: Draw = [12, 13, 14];
+        let id_sum: usize = games
+            .iter()
+            .filter(|game| {
+                game.draws
+                    .iter()
+                    .all(|draw| is_draw_possible(&bag_configuration, draw))
+            })
+            .map(|game| game.id)
+            .sum();
+        Ok(id_sum.into())
+    }
+
+    fn part_two(&self, games: &Self::Parsed<'_>) -> Result<Answer> {
+        let power_sum: i32 = games
+            .iter()
+            .map(find_minimum_configuration)
+            .map(|c| c.iter().product::<i32>())
+            .sum();
+        Ok(power_sum.into())
+    }
 }
@@ -1,173 +1,91 @@
-use std::ops::Range;
+use aocutils::{register_day, Answer, Grid, Input, Point, Result, Solution};
 
-#[derive(Debug)]
-struct Bound2D {
-    x_range: Range<usize>,
-    y_range: Range<usize>,
+fn is_symbol(cell: &u8) -> bool {
+    *cell != b'.' && !cell.is_ascii_digit()
 }
 
-fn enlarge_range(range: &Range<usize>, extent: usize) -> Range<usize> {
-    range.start.saturating_sub(extent)..range.end + extent
+pub struct Schematic {
+    grid: Grid<u8>,
+    /// The value of every number.
+    numbers: Vec<u32>,
+    /// Which number, if any, covers each cell.
+    number_at: Grid<Option<usize>>,
 }
 
-impl Bound2D {
-    fn contains(&self, p: (usize, usize)) -> bool {
-        self.x_range.contains(&p.0) && self.y_range.contains(&p.1)
+impl Schematic {
+    /// The numbers next to `position`, each only once.
+    fn adjacent_numbers(&self, position: Point) -> Vec<usize> {
+        let mut adjacent: Vec<_> = self
+            .grid
+            .neighbours8(position)
+            .filter_map(|neighbour| self.number_at[neighbour])
+            .collect();
+        adjacent.sort();
+        adjacent.dedup();
+        adjacent
     }
 
-    fn enlarge(&self, extent: (usize, usize)) -> Self {
-        Bound2D {
-            x_range: enlarge_range(&self.x_range, extent.0),
-            y_range: enlarge_range(&self.y_range, extent.1),
-        }
+    fn symbols(&self) -> impl Iterator<Item = Point> + '_ {
+        self.grid.find_all(is_symbol)
     }
 }
 
-#[derive(Debug)]
-struct Number {
-    bound: Bound2D,
-    value: u32,
-}
+pub struct Day3;
 
-pub fn day3(input: Vec<String>) {
-    let numbers: Vec<_> = input
-        .iter()
-        .enumerate()
-        .map(|(y, line)| {
-            let digit_indices: Vec<_> = line
-                .chars()
-                .into_iter()
-                .enumerate()
-                .filter_map(|(x, char)| match char.is_digit(10) {
-                    true => Some(x),
-                    false => None,
-                })
-                .collect();
-            let ranges = digit_indices
-                .iter()
-                .fold(Vec::<Range<usize>>::new(), |mut acc, x| {
-                    if acc.is_empty() {
-                        acc.push(*x..*x + 1);
-                    } else {
-                        let last_element = acc.last_mut().unwrap();
-                        if last_element.end == *x {
-                            *last_element = last_element.start..x + 1;
-                        } else {
-                            acc.push(*x..*x + 1);
-                        }
-                    }
-                    acc
-                });
-            ranges
-                .into_iter()
-                .map(move |range| Number {
-                    bound: Bound2D {
-                        x_range: range.clone(),
-                        y_range: y..y + 1,
-                    },
-                    value: line.as_str()[range].parse::<u32>().unwrap(),
-                })
-                .collect::<Vec<_>>()
-        })
-        .flatten()
-        .collect();
+register_day!(2023, 3, "Gear Ratios", Day3);
 
-    let symbol_positions: Vec<_> = input
-        .iter()
-        .enumerate()
-        .map(|(y, line)| {
-            let symbol_indices: Vec<_> = line
-                .chars()
-                .into_iter()
-                .enumerate()
-                .filter_map(|(x, char)| {
-                    if char != '.' && !char.is_digit(10) {
-                        Some(x)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-            symbol_indices
-                .into_iter()
-                .map(move |symbol_x| (symbol_x, y))
-                .collect::<Vec<_>>()
-        })
-        .flatten()
-        .collect();
+impl Solution for Day3 {
+    type Parsed<'a> = Schematic;
 
-    let numbers_filtered: Vec<_> = numbers
-        .iter()
-        .filter_map(|number| {
-            if symbol_positions
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        let grid = Grid::parse(input, Some)?;
+
+        let mut numbers = Vec::new();
+        let mut number_at = Grid::new(grid.width(), grid.height(), None);
+        for span in grid.spans(u8::is_ascii_digit) {
+            let row = grid.row(span.y).expect("spans are inside the grid");
+            let value = row[span.x.clone()]
                 .iter()
-                .any(|position| number.bound.enlarge((1, 1)).contains(*position))
-            {
-                Some(number)
-            } else {
-                None
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+            for position in span.positions() {
+                number_at[position] = Some(numbers.len());
             }
-        })
-        .collect();
+            numbers.push(value);
+        }
 
-    let gear_positions: Vec<_> = input
-        .iter()
-        .enumerate()
-        .map(|(y, line)| {
-            let symbol_indices: Vec<_> = line
-                .chars()
-                .into_iter()
-                .enumerate()
-                .filter_map(|(x, char)| if char == '*' { Some(x) } else { None })
-                .collect();
-            symbol_indices
-                .into_iter()
-                .map(move |symbol_x| (symbol_x, y))
-                .collect::<Vec<_>>()
+        Ok(Schematic {
+            grid,
+            numbers,
+            number_at,
         })
-        .flatten()
-        .collect();
+    }
 
-    let gear_ratio_sum: u32 = gear_positions
-        .iter()
-        .filter_map(|position| {
-            let neighbors: Vec<_> = numbers
-                .iter()
-                .filter(|number| number.bound.enlarge((1, 1)).contains(*position))
-                .collect();
-            if neighbors.len() == 2 {
-                Some((neighbors[0], neighbors[1]))
-            } else {
-                None
+    fn part_one(&self, schematic: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut is_part = vec![false; schematic.numbers.len()];
+        for symbol in schematic.symbols() {
+            for number in schematic.adjacent_numbers(symbol) {
+                is_part[number] = true;
             }
-        })
-        .map(|(first, second)| (*first).value * (*second).value)
-        .sum();
-    let sum: u32 = numbers_filtered.iter().map(|&number| (*number).value).sum();
-
-    println!("The answer for part one is {sum}");
-    println!("The answer for part two is {gear_ratio_sum}");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn bound_contains() {
-        let bound = Bound2D {
-            x_range: 0..2,
-            y_range: 0..2,
-        };
+        }
 
-        assert!(bound.contains((0, 0)));
-        assert!(bound.contains((0, 1)));
-        assert!(bound.contains((1, 0)));
-        assert!(bound.contains((1, 1)));
+        let sum: u32 = schematic
+            .numbers
+            .iter()
+            .zip(is_part)
+            .filter(|(_, is_part)| *is_part)
+            .map(|(value, _)| value)
+            .sum();
+        Ok(sum.into())
+    }
 
-        assert!(!bound.contains((0, 2)));
-        assert!(!bound.contains((2, 0)));
-        assert!(!bound.contains((1, 2)));
-        assert!(!bound.contains((2, 2)));
+    fn part_two(&self, schematic: &Self::Parsed<'_>) -> Result<Answer> {
+        let gear_ratio_sum: u32 = schematic
+            .symbols()
+            .filter(|position| schematic.grid[*position] == b'*')
+            .map(|position| schematic.adjacent_numbers(position))
+            .filter(|neighbors| neighbors.len() == 2)
+            .map(|neighbors| schematic.numbers[neighbors[0]] * schematic.numbers[neighbors[1]])
+            .sum();
+        Ok(gear_ratio_sum.into())
     }
 }
@@ -1,7 +1,7 @@
-use regex::Regex;
+use aocutils::{register_day, Answer, Input, Line, Result, Solution};
 
 #[derive(Debug, Clone)]
-struct Card {
+pub struct Card {
     winning_numbers: Vec<i32>,
     numbers: Vec<i32>,
     amount: usize,
@@ -11,12 +11,7 @@ impl Card {
     fn winning_numbers(&self) -> i32 {
         self.numbers
             .iter()
-            .filter_map(|n| {
-                self.winning_numbers
-                    .iter()
-                    .any(|winner| winner == n)
-                    .then(|| n)
-            })
+            .filter(|n| self.winning_numbers.iter().any(|winner| winner == *n))
             .count() as i32
     }
 
@@ -27,58 +22,54 @@ impl Card {
             return 0;
         }
 
-        (2 as i32).pow((winning_count as u32).saturating_sub(1))
+        2_i32.pow((winning_count as u32).saturating_sub(1))
     }
 }
 
-fn parse_numbers(input: &str) -> Option<Vec<i32>> {
-    Some(
-        input
-            .split_terminator(" ")
-            .filter_map(|n| n.parse::<i32>().ok())
-            .collect(),
-    )
-}
-
-fn parse_card(input: &String) -> Option<Card> {
-    let re = Regex::new(r"Card\s+(?<id>\d+):").ok()?;
-    let id_match = re.captures(input)?.name("id")?;
-
-    let numbers_input = input[id_match.end() + 1..].to_string();
-    let mut number_lists = numbers_input.split_terminator("|");
+fn parse_card(line: Line) -> Result<Card> {
+    let (id, lists) = line.key_lists()?;
+    id.strip_prefix("Card")?.trim().parse::<usize>()?;
+    let [winning_numbers, numbers] = <[Vec<i32>; 2]>::try_from(lists).map_err(|lists| {
+        line.error(format!("expected 2 lists of numbers, found {}", lists.len()))
+    })?;
 
-    let winning_numbers = parse_numbers(number_lists.next()?)?;
-    let numbers = parse_numbers(number_lists.next()?)?;
-
-    Some(Card {
+    Ok(Card {
         winning_numbers,
         numbers,
         amount: 1,
     })
 }
 
-pub fn day4(input: Vec<String>) {
-    let cards: Vec<_> = input.iter().filter_map(parse_card).collect();
-    let score: i32 = cards.iter().map(Card::score).sum();
-    println!("The score of all cards sums up to {score}");
+pub struct Day4;
+
+register_day!(2023, 4, "Scratchcards", Day4);
+
+impl Solution for Day4 {
+    type Parsed<'a> = Vec<Card>;
 
-    let mut final_cards = cards.clone();
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        input.parse_lines(parse_card)
+    }
+
+    fn part_one(&self, cards: &Self::Parsed<'_>) -> Result<Answer> {
+        let score: i32 = cards.iter().map(Card::score).sum();
+        Ok(score.into())
+    }
 
-    cards
-        .iter()
-        .enumerate()
-        .map(|(index, card)| {
+    fn part_two(&self, cards: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut final_cards = cards.clone();
+
+        for (index, card) in cards.iter().enumerate() {
             let winning_numbers = card.winning_numbers() as usize;
-            let num_copies = *(&final_cards[index].amount);
+            let num_copies = final_cards[index].amount;
             final_cards
                 .iter_mut()
                 .skip(index + 1)
                 .take(winning_numbers)
-                .map(|winner| winner.amount += num_copies)
-                .for_each(|_| ());
-        })
-        .for_each(|_| ());
+                .for_each(|winner| winner.amount += num_copies);
+        }
 
-    let total_cards_num: usize = final_cards.iter().map(|c| c.amount).sum();
-    println!("At the end we have {total_cards_num} cards.");
+        let total_cards_num: usize = final_cards.iter().map(|c| c.amount).sum();
+        Ok(total_cards_num.into())
+    }
 }
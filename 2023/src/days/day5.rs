@@ -1,151 +1,148 @@
-use regex::Regex;
-use std::{ops::Range, usize};
-
-#[derive(Debug, Clone, PartialEq)]
-enum Kind {
-    Seed,
-    Soil,
-    Fertilizer,
-    Water,
-    Light,
-    Temperature,
-    Humidity,
-    Location,
+use aocutils::{
+    aoc_example,
+    interval::{OffsetMap, RangeSet},
+    named_enum, register_day, Answer, Error, Input, Line, Result, Solution,
+};
+use std::ops::Range;
+
+named_enum! {
+    pub enum Kind {
+        Seed => "seed",
+        Soil => "soil",
+        Fertilizer => "fertilizer",
+        Water => "water",
+        Light => "light",
+        Temperature => "temperature",
+        Humidity => "humidity",
+        Location => "location",
+    }
 }
 
-fn parse_seeds(input: &String) -> Option<Vec<(Kind, usize)>> {
-    let seeds: Vec<_> = input
-        .split(|c| c == ' ')
-        .skip(1)
-        .map(|n| n.parse::<usize>().unwrap())
-        .map(|amount| (Kind::Seed, amount))
-        .collect();
-    Some(seeds)
+fn parse_seeds(line: Line) -> Result<Vec<i64>> {
+    line.strip_prefix("seeds:")?.field_list()
 }
 
-fn parse_seed_ranges(input: &String) -> Option<Vec<(Kind, Range<usize>)>> {
-    let numbers = input.split(|c| c == ' ').skip(1).collect::<Vec<_>>();
-    let chunks: Vec<_> = numbers.chunks(2).collect();
-    let ranges: Vec<_> = chunks
-        .iter()
-        .filter_map(|c| Some((c[0].parse::<usize>().ok()?, c[1].parse::<usize>().ok()?)))
-        .map(|(start, len)| (Kind::Seed, start..start + len))
-        .collect();
-    Some(ranges)
+/// The seeds of `line` read as pairs of a start and a length.
+fn seed_ranges(line: Line, seeds: &[i64]) -> Result<RangeSet<i64>> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(line.error("expected the seeds in pairs of a start and a length"));
+    }
+    seeds
+        .chunks_exact(2)
+        .map(|pair| match pair[0].checked_add(pair[1]) {
+            Some(end) => Ok(pair[0]..end),
+            None => Err(line.error(format!("the seed range {pair:?} is too large"))),
+        })
+        .collect()
 }
 
+/// The map from one kind of almanac entry to the next.
 #[derive(Debug)]
-struct Mapping {
-    from: Vec<(Kind, Range<usize>)>,
-    to: Vec<(Kind, Range<usize>)>,
+pub struct Mapping {
+    from: Kind,
+    to: Kind,
+    map: OffsetMap,
 }
 
-impl Mapping {
-    fn map(&self, item: (Kind, usize)) -> Option<(Kind, usize)> {
-        let candidate = self
-            .from
-            .iter()
-            .position(|(kind, range)| *kind == item.0 && range.contains(&item.1));
-        if candidate.is_none() {
-            return Some((self.to[0].0.clone(), item.1));
-        }
-
-        let (_, from_range) = &self.from[candidate.unwrap()];
-        let (to_kind, to_range) = &self.to[candidate.unwrap()];
-
-        Some((to_kind.clone(), item.1 + to_range.start - from_range.start))
-    }
+fn parse_mapping_entry(line: Line) -> Result<(Range<i64>, i64)> {
+    let [destination_start, source_start, length]: [i64; 3] = line.parse_fields()?;
+    let source_end = source_start
+        .checked_add(length)
+        .ok_or_else(|| line.error("the source range is too large"))?;
+    let offset = destination_start
+        .checked_sub(source_start)
+        .ok_or_else(|| line.error("the destination is too far from the source"))?;
+    Ok((source_start..source_end, offset))
 }
 
-fn parse_kind(input: &str) -> Option<Kind> {
-    match input {
-        "seed" => Some(Kind::Seed),
-        "soil" => Some(Kind::Soil),
-        "fertilizer" => Some(Kind::Fertilizer),
-        "water" => Some(Kind::Water),
-        "light" => Some(Kind::Light),
-        "temperature" => Some(Kind::Temperature),
-        "humidity" => Some(Kind::Humidity),
-        "location" => Some(Kind::Location),
-        _ => None,
+/// Parses a paragraph made of a header like `seed-to-soil map:` and its entries.
+fn parse_mappings(input: &Input, header: Line, entries: &[Line]) -> Result<Mapping> {
+    let (from, to) = header.strip_suffix(" map:")?.split_once("-to-")?;
+    let (from, to) = (from.parse()?, to.parse()?);
+
+    let mut map = OffsetMap::identity();
+    let parsed = input.parse_all(entries.iter().copied(), |line| {
+        Ok((line, parse_mapping_entry(line)?))
+    })?;
+    for (line, (range, offset)) in parsed {
+        map.insert(range, offset)
+            .map_err(|other| line.error(format!("overlaps the sources {other:?}")))?;
     }
-}
-
-fn parse_mapping_entry(input: &String) -> Option<(Range<usize>, Range<usize>)> {
-    let mut parts = input.split(" ");
-    let destination_start = parts.next()?.parse::<usize>().ok()?;
-    let source_start = parts.next()?.parse::<usize>().ok()?;
-    let length = parts.next()?.parse::<usize>().ok()?;
 
-    Some((
-        source_start..source_start + length,
-        destination_start..destination_start + length,
-    ))
+    Ok(Mapping { from, to, map })
 }
 
-fn parse_mappings(input: &[String]) -> Option<Mapping> {
-    let re = Regex::new(r"(?<from>[^-]+)-to-(?<to>[^\s]+) map:").ok()?;
-
-    let captures = re.captures(&input[0])?;
-    let from = parse_kind(captures.name("from")?.as_str())?;
-    let to = parse_kind(captures.name("to")?.as_str())?;
-
-    let (from, to): (Vec<_>, Vec<_>) = input
-        .iter()
-        .skip(1)
-        .filter_map(parse_mapping_entry)
-        .map(|(l, r)| ((from.clone(), l), (to.clone(), r)))
-        .unzip();
-
-    Some(Mapping { from, to })
+pub struct Almanac {
+    seeds: Vec<i64>,
+    seed_ranges: RangeSet<i64>,
+    /// All mappings from seed to location in one.
+    seed_to_location: OffsetMap,
 }
 
-fn map_seed(mappings: &Vec<Mapping>, seed: &(Kind, usize)) -> (Kind, usize) {
-    mappings.iter().fold(seed.clone(), |acc, m| {
-        let result = m.map(acc).unwrap();
-        result
+fn parse_almanac(input: &Input) -> Result<Almanac> {
+    let mut paragraphs = input.paragraph_lines();
+    let seed_line = match paragraphs.next().as_deref() {
+        Some([line]) => *line,
+        Some([_, line, ..]) => return Err(line.error("expected a blank line after the seeds")),
+        _ => return Err(Error::parse(1, "expected the seeds")),
+    };
+    let seeds = parse_seeds(seed_line)?;
+    let seed_ranges = seed_ranges(seed_line, &seeds)?;
+
+    let mut kind = Kind::Seed;
+    let mut seed_to_location = OffsetMap::identity();
+    for paragraph in paragraphs {
+        let (header, entries) = paragraph.split_first().expect("paragraphs are not empty");
+        let mapping = parse_mappings(input, *header, entries)?;
+        if mapping.from != kind {
+            return Err(header.error(format!(
+                "expected a map from {kind:?}, found one from {:?}",
+                mapping.from
+            )));
+        }
+        seed_to_location = seed_to_location.then(&mapping.map);
+        kind = mapping.to;
+    }
+    if kind != Kind::Location {
+        let last = input.lines().count();
+        return Err(Error::parse(last, format!("the maps end at {kind:?}, not at the location")));
+    }
+
+    Ok(Almanac {
+        seeds,
+        seed_ranges,
+        seed_to_location,
     })
 }
 
-pub fn day5(input: Vec<String>) {
-    let input_fragments: Vec<_> = input.split(|line| line.as_str() == "").collect();
+#[aoc_example(file = "inputs/day-5-test.txt", part_one = 35, part_two = 46)]
+pub struct Day5;
+
+register_day!(2023, 5, "If You Give A Seed A Fertilizer", Day5);
+
+impl Solution for Day5 {
+    type Parsed<'a> = Almanac;
 
-    if input_fragments.len() == 0 {
-        panic!("Invalid input.");
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        parse_almanac(input)
     }
 
-    let seeds = parse_seeds(&input_fragments[0][0])
-        .or_else(|| {
-            panic!("Failed to parse seeds.");
-        })
-        .unwrap();
-
-    let mappings: Vec<_> = input_fragments
-        .iter()
-        .skip(1)
-        .filter_map(|fragment| parse_mappings(*fragment))
-        .collect();
-    let locations: Vec<_> = seeds.iter().map(|s| map_seed(&mappings, s)).collect();
-    let min = locations.iter().map(|(_, l)| l).min().unwrap();
-    println!("The minimum location is {min}.");
-
-    let seed_ranges = parse_seed_ranges(&input_fragments[0][0])
-        .or_else(|| {
-            panic!("Failed to parse seed ranges.");
-        })
-        .unwrap();
-
-    let location = seed_ranges
-        .iter()
-        .map(|(kind, range)| {
-            range
-                .clone()
-                .map(|s| map_seed(&mappings, &(kind.clone(), s)))
-        })
-        .flatten()
-        .map(|(_, l)| l)
-        .min()
-        .unwrap();
+    fn part_one(&self, almanac: &Self::Parsed<'_>) -> Result<Answer> {
+        let min = almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.seed_to_location.map(*seed))
+            .min()
+            .ok_or_else(|| Error::parse(1, "expected at least one seed"))?;
+        Ok(min.into())
+    }
 
-    println!("The minimum location for seeds parsed as ranges is {location}.");
+    fn part_two(&self, almanac: &Self::Parsed<'_>) -> Result<Answer> {
+        let location = almanac
+            .seed_to_location
+            .map_set(&almanac.seed_ranges)
+            .min()
+            .ok_or_else(|| Error::parse(1, "expected at least one seed range"))?;
+        Ok(location.into())
+    }
 }
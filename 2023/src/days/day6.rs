@@ -1,65 +1,63 @@
-fn parse_numbers(input: &String) -> Vec<usize> {
-    input
-        .split(" ")
-        .filter(|e| *e != "")
-        .skip(1)
-        .filter_map(|e| e.parse::<usize>().ok())
-        .collect()
-}
+use aocutils::{
+    aoc_example, math::count_splits_above, register_day, Answer, Input, Line, Result, Solution,
+};
+
+/// The numbers after `label`, and the single number they make when the
+/// spaces between them are ignored.
+fn parse_numbers(line: Line, label: &str) -> Result<(Vec<u64>, u64)> {
+    let fields = line.strip_prefix(label)?;
+    let numbers: Vec<u64> = fields.field_list()?;
+    if numbers.is_empty() {
+        return Err(fields.error("expected at least one number"));
+    }
 
-fn parse_number(input: &String) -> Option<usize> {
-    input
-        .split(" ")
-        .filter(|e| *e != "")
-        .skip(1)
-        .collect::<String>()
-        .parse::<usize>()
-        .ok()
+    let mut joined = 0_u64;
+    for (field, number) in fields.fields().zip(&numbers) {
+        joined = 10_u64
+            .checked_pow(field.as_str().len() as u32)
+            .and_then(|shift| joined.checked_mul(shift)?.checked_add(*number))
+            .ok_or_else(|| field.error("the numbers are too long to join"))?;
+    }
+    Ok((numbers, joined))
 }
 
-fn compute_number_of_wins(time: usize, distance: usize) -> usize {
-    /*
-     * We want to intersect `time * (time - t)` with `distance`.
-     * The maximum will be reached at `time / 2.` and the intersection
-     * points have the same distance to the maximum for symmetry reasons.
-     *
-     * So we can look for the first intersection point and then calculate
-     * calculate the number of ways to win from that.
-     */
-    let maximum_time = time as f64 / 2.;
-    let left_winning_time = (1..time)
-        .filter_map(|t| {
-            if t * (time - t) > distance {
-                Some(t)
-            } else {
-                None
-            }
-        })
-        .take(1)
-        .next()
-        .unwrap();
-    ((maximum_time - left_winning_time as f64) * 2. + 1.) as usize
+pub struct Races {
+    /// The time and record distance of every race.
+    races: Vec<(u64, u64)>,
+    /// The single long race we get when ignoring the spaces.
+    long_race: (u64, u64),
 }
 
-pub fn day6(input: Vec<String>) {
-    let times = parse_numbers(&input[0]);
-    let distances = parse_numbers(&input[1]);
+#[aoc_example(file = "inputs/day-6-test.txt", part_one = 288, part_two = 71503)]
+#[aoc_example(input = "Time: 30\nDistance: 200\n", part_one = 9)]
+pub struct Day6;
 
-    let result: usize = times
-        .iter()
-        .zip(distances.iter())
-        .map(|(t, d)| compute_number_of_wins(*t, *d))
-        .product();
+register_day!(2023, 6, "Wait For It", Day6);
 
-    println!("The answer for part one is {result}.");
+impl Solution for Day6 {
+    type Parsed<'a> = Races;
+
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        let (times, time) = parse_numbers(input.line(0)?, "Time:")?;
+        let (distances, distance) = parse_numbers(input.line(1)?, "Distance:")?;
+
+        Ok(Races {
+            races: times.into_iter().zip(distances).collect(),
+            long_race: (time, distance),
+        })
+    }
 
-    let time = parse_number(&input[0])
-        .or_else(|| panic!("Could not parse time."))
-        .unwrap();
-    let distance = parse_number(&input[1])
-        .or_else(|| panic!("Could not parse distance."))
-        .unwrap();
+    fn part_one(&self, races: &Self::Parsed<'_>) -> Result<Answer> {
+        let result: u64 = races
+            .races
+            .iter()
+            .map(|(time, record)| count_splits_above(*time, *record))
+            .product();
+        Ok(result.into())
+    }
 
-    let number_of_wins = compute_number_of_wins(time, distance);
-    println!("There are {number_of_wins} ways to win the long race.");
+    fn part_two(&self, races: &Self::Parsed<'_>) -> Result<Answer> {
+        let (time, distance) = races.long_race;
+        Ok(count_splits_above(time, distance).into())
+    }
 }
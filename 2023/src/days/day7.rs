@@ -1,180 +1,197 @@
-use std::{cmp::Ordering, collections::HashMap};
-
-#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
-enum Card {
-    Two,
-    Three,
-    Four,
-    Five,
-    Six,
-    Seven,
-    Eight,
-    Nine,
-    Ten,
-    Joker,
-    Queen,
-    King,
-    Ace,
+use aocutils::{aoc_example, named_enum, register_day, Answer, Input, Line, Result, Solution};
+
+named_enum! {
+    /// The cards from the weakest to the strongest, unless the [`Rules`]
+    /// rank them differently.
+    enum Card {
+        Two => "2",
+        Three => "3",
+        Four => "4",
+        Five => "5",
+        Six => "6",
+        Seven => "7",
+        Eight => "8",
+        Nine => "9",
+        Ten => "T",
+        Jack => "J",
+        Queen => "Q",
+        King => "K",
+        Ace => "A",
+    }
 }
 
-fn strength(card: &Card) -> usize {
-    match card {
-        Card::Two => 1,
-        Card::Three => 2,
-        Card::Four => 3,
-        Card::Five => 4,
-        Card::Six => 5,
-        Card::Seven => 6,
-        Card::Eight => 7,
-        Card::Nine => 8,
-        Card::Ten => 9,
-        Card::Joker => 10,
-        Card::Queen => 11,
-        Card::King => 12,
-        Card::Ace => 13,
+named_enum! {
+    /// The kinds of hands from the weakest to the strongest.
+    enum HandType {
+        HighCard => "high card",
+        OnePair => "one pair",
+        TwoPair => "two pair",
+        Three => "three of a kind",
+        FullHouse => "full house",
+        Four => "four of a kind",
+        Five => "five of a kind",
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum HandType {
-    HighCard,
-    OnePair,
-    TwoPair,
-    Three,
-    FullHouse,
-    Four,
-    Five,
+/// How the cards rank and which card, if any, is wild.
+struct Rules {
+    /// The cards from the weakest to the strongest.
+    order: [Card; Card::ALL.len()],
+    wildcard: Option<Card>,
 }
 
-fn parse_card(input: char) -> Option<Card> {
-    match input {
-        '2' => Some(Card::Two),
-        '3' => Some(Card::Three),
-        '4' => Some(Card::Four),
-        '5' => Some(Card::Five),
-        '6' => Some(Card::Six),
-        '7' => Some(Card::Seven),
-        '8' => Some(Card::Eight),
-        '9' => Some(Card::Nine),
-        'T' => Some(Card::Ten),
-        'J' => Some(Card::Joker),
-        'Q' => Some(Card::Queen),
-        'K' => Some(Card::King),
-        'A' => Some(Card::Ace),
-        _ => None,
+/// Part one: jacks are just jacks.
+const STANDARD: Rules = Rules {
+    order: Card::ALL,
+    wildcard: None,
+};
+
+/// Part two: jacks are jokers, which are the weakest card but count as any other.
+const JOKERS: Rules = Rules {
+    order: [
+        Card::Jack,
+        Card::Two,
+        Card::Three,
+        Card::Four,
+        Card::Five,
+        Card::Six,
+        Card::Seven,
+        Card::Eight,
+        Card::Nine,
+        Card::Ten,
+        Card::Queen,
+        Card::King,
+        Card::Ace,
+    ],
+    wildcard: Some(Card::Jack),
+};
+
+impl Rules {
+    /// The position of `card` in the order, so stronger cards are larger.
+    fn strength(&self, card: Card) -> usize {
+        self.order
+            .iter()
+            .position(|ranked| *ranked == card)
+            .expect("every card is ranked")
+    }
+
+    fn hand_type(&self, cards: &[Card; 5]) -> HandType {
+        let mut counts = [0_u8; Card::ALL.len()];
+        let mut wildcards = 0;
+        for card in cards {
+            match Some(*card) == self.wildcard {
+                true => wildcards += 1,
+                false => counts[card.index()] += 1,
+            }
+        }
+        counts.sort_unstable_by(|l, r| r.cmp(l));
+        // Wildcards are best used to join the most common card.
+        counts[0] += wildcards;
+
+        match (counts[0], counts[1]) {
+            (5, _) => HandType::Five,
+            (4, _) => HandType::Four,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::Three,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
     }
-}
 
-fn count_amounts(cards: Vec<Card>) -> Vec<(Card, usize)> {
-    let mut amounts = HashMap::new();
-    for card in cards {
-        *amounts.entry(card).or_insert(0 as usize) += 1;
+    /// Sorting by this key ranks the hands from the weakest to the strongest.
+    fn key(&self, hand: &Hand) -> (HandType, [usize; 5]) {
+        (
+            self.hand_type(&hand.cards),
+            hand.cards.map(|card| self.strength(card)),
+        )
     }
 
-    let mut result = amounts
-        .keys()
-        .zip(amounts.values())
-        .map(|(c, a)| (*c, *a))
-        .collect::<Vec<_>>();
-    result.sort_by(|(_, l), (_, r)| l.cmp(r).reverse());
+    fn total_winnings(&self, hands: &[Hand]) -> usize {
+        let mut hands: Vec<_> = hands.iter().collect();
+        hands.sort_by_cached_key(|hand| self.key(hand));
 
-    result
+        hands
+            .iter()
+            .enumerate()
+            .map(|(rank, hand)| (rank + 1) * hand.bid)
+            .sum()
+    }
 }
 
-#[derive(Debug, Eq, PartialEq, PartialOrd)]
-struct Hand {
-    cards: Vec<Card>,
-    cards_sorted: Vec<Card>,
-    amounts: Vec<(Card, usize)>,
+#[derive(Debug, Eq, PartialEq)]
+pub struct Hand {
+    cards: [Card; 5],
     bid: usize,
-    line: String,
 }
 
-impl Hand {
-    fn hand_type(&self) -> Option<HandType> {
-        let mut amount_it = self.amounts.iter();
-
-        match amount_it.next()? {
-            (_, 5) => Some(HandType::Five),
-            (_, 4) => Some(HandType::Four),
-            (_, 3) => match amount_it.next()? {
-                (_, 2) => Some(HandType::FullHouse),
-                (_, 1) => Some(HandType::Three),
-                _ => None,
-            },
-            (_, 2) => match amount_it.next()? {
-                (_, 2) => Some(HandType::TwoPair),
-                (_, 1) => Some(HandType::OnePair),
-                _ => None,
-            },
-            _ => Some(HandType::HighCard),
-        }
-    }
+fn parse_hand(line: Line) -> Result<Hand> {
+    let (cards, bid) = line.split_once(" ")?;
+    let parsed: Vec<_> = cards
+        .as_str()
+        .char_indices()
+        .map(|(offset, card)| {
+            Card::try_from(card)
+                .map_err(|error| cards.error_at(offset, format!("invalid card {card:?}: {error}")))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Hand {
+        cards: parsed.try_into().map_err(|parsed: Vec<_>| {
+            cards.error(format!("expected 5 cards, found {}", parsed.len()))
+        })?,
+        bid: bid.parse()?,
+    })
 }
 
-impl Ord for Hand {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if let (Some(l), Some(r)) = (self.hand_type(), other.hand_type()) {
-            match l.cmp(&r) {
-                Ordering::Less => return Ordering::Less,
-                Ordering::Equal => (),
-                Ordering::Greater => return Ordering::Greater,
-            };
-        }
+#[aoc_example(file = "inputs/day-7-test.txt", part_one = 6440, part_two = 5905)]
+pub struct Day7;
 
-        let mut my_card = self.cards.iter();
-        let mut other_card = other.cards.iter();
-
-        loop {
-            let (left, right) = (my_card.next(), other_card.next());
-            match (left, right) {
-                (None, _) => {
-                    return Ordering::Less;
-                }
-                (_, None) => {
-                    return Ordering::Greater;
-                }
-                (Some(l), Some(r)) => match l.cmp(&r) {
-                    Ordering::Less => {
-                        return Ordering::Less;
-                    }
-                    Ordering::Equal => (),
-                    Ordering::Greater => {
-                        return Ordering::Greater;
-                    }
-                },
-            }
-        }
+register_day!(2023, 7, "Camel Cards", Day7);
+
+impl Solution for Day7 {
+    type Parsed<'a> = Vec<Hand>;
+
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        input.parse_lines(parse_hand)
     }
-}
 
-fn parse_hand(input: &String) -> Option<Hand> {
-    let mut s = input.split(" ");
-    let cards: Vec<_> = s
-        .next()?
-        .chars()
-        .into_iter()
-        .filter_map(parse_card)
-        .collect();
-    let mut cards_sorted = cards.clone();
-    cards_sorted.sort_by(|l, r| strength(l).cmp(&strength(r)).reverse());
-    let bid = s.next()?.parse::<usize>().ok()?;
-
-    let amounts = count_amounts(cards.clone());
-
-    Some(Hand {
-        cards,
-        cards_sorted,
-        amounts,
-        bid,
-        line: input.clone(),
-    })
+    fn part_one(&self, hands: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(STANDARD.total_winnings(hands).into())
+    }
+
+    fn part_two(&self, hands: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(JOKERS.total_winnings(hands).into())
+    }
 }
 
-pub fn day7(input: Vec<String>) {
-    let mut hands: Vec<_> = input.iter().filter_map(parse_hand).collect();
-    hands.sort_by(|l, r| l.cmp(&r));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand_type(rules: &Rules, cards: &str) -> HandType {
+        let line = format!("{cards} 0");
+        rules.hand_type(&parse_hand(Line::new(&line, 1)).unwrap().cards)
+    }
+
+    #[test]
+    fn jokers_upgrade_hands() {
+        assert_eq!(hand_type(&STANDARD, "KTJJT"), HandType::TwoPair);
+        assert_eq!(hand_type(&JOKERS, "KTJJT"), HandType::Four);
+        assert_eq!(hand_type(&JOKERS, "T55J5"), HandType::Four);
+        assert_eq!(hand_type(&JOKERS, "2345J"), HandType::OnePair);
+        assert_eq!(hand_type(&JOKERS, "JJJJJ"), HandType::Five);
+        assert!(STANDARD.strength(Card::Jack) > STANDARD.strength(Card::Ten));
+        assert!(JOKERS.strength(Card::Jack) < JOKERS.strength(Card::Two));
+    }
 
-    let total_winnings: usize = hands.iter().enumerate().map(|(r, c)| (r + 1) * c.bid).sum();
-    println!("The total winnings are {total_winnings}.");
+    #[test]
+    fn malformed_hands() {
+        let error = parse_hand(Line::new("32T3X 765", 4)).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("failed to parse line 4, column 5: invalid card 'X'"));
+        assert!(parse_hand(Line::new("32T3 765", 4)).is_err());
+        assert!(parse_hand(Line::new("32T3K", 4)).is_err());
+    }
 }
@@ -1,11 +1,30 @@
-use std::env;
-use aocutils::utility::parse_execution_mode;
-
 pub mod days;
 
-pub fn run() {
-    let mut args = env::args();
-    let day = args.nth(1).unwrap_or("1".to_string());
-    let execution_mode = parse_execution_mode(args.next().unwrap_or("test".to_string()));
-    days::execute_day(day, execution_mode);
+#[cfg(test)]
+mod tests {
+    use aocutils::{
+        answers::{verify_day, Verdict},
+        locate::InputLocator,
+        registry, ExecutionMode,
+    };
+
+    #[test]
+    fn expected_answers() {
+        let locator = InputLocator::default();
+        for day in registry::days_of_year(2023) {
+            for mode in [ExecutionMode::Normal]
+                .into_iter()
+                .chain(locator.examples(day))
+            {
+                let verdicts = verify_day(&locator, day, &mode).unwrap();
+                for verdict in verdicts {
+                    assert!(
+                        !matches!(verdict, Verdict::Fail { .. }),
+                        "Day {} ({mode}): {verdict:?}",
+                        day.day
+                    );
+                }
+            }
+        }
+    }
 }
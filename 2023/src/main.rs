@@ -1,5 +0,0 @@
-use adventofcode::run;
-
-fn main() {
-    run();
-}
@@ -0,0 +1,3 @@
+fn main() {
+    aocbuild::declare_days();
+}
@@ -1,8 +1,21 @@
-pub fn day1(input: Vec<String>) {
-    println!("Day 1 {input:?}");
-}
+use aocutils::{Answer, Input, Result, Solution, register_day};
+
+pub struct Day1;
+
+register_day!(2025, 1, "Secret Entrance", Day1);
+
+impl Solution for Day1 {
+    type Parsed<'a> = &'a Input;
+
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        Ok(input)
+    }
+
+    fn part_one(&self, _input: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn part_two(&self, _input: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
 }
@@ -1,5 +0,0 @@
-use adventofcode2025::run;
-
-fn main() {
-    run();
-}
@@ -0,0 +1,37 @@
+//! What the build scripts of the year crates need. It has no dependencies, so
+//! building it stays cheap.
+
+use std::{env, fs, path::Path};
+
+/// Declares a module for every `dayN.rs` in `src/days`.
+///
+/// Meant to be called from a year crate's build script. The generated file is
+/// pulled into `days/mod.rs` with
+/// `include!(concat!(env!("OUT_DIR"), "/days.rs"));`, so adding a new day only
+/// requires adding its file.
+pub fn declare_days() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    let days_dir = Path::new(&manifest_dir).join("src").join("days");
+
+    println!("cargo:rerun-if-changed={}", days_dir.display());
+
+    let mut days: Vec<_> = fs::read_dir(&days_dir)
+        .expect("Failed to read src/days")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_stem()?.to_str()?;
+            let number = name.strip_prefix("day")?.parse::<u8>().ok()?;
+            (path.extension()? == "rs").then_some((number, path))
+        })
+        .collect();
+    days.sort();
+
+    let declarations: String = days
+        .iter()
+        .map(|(number, path)| format!("#[path = {:?}]\npub mod day{number};\n", path))
+        .collect();
+
+    fs::write(Path::new(&out_dir).join("days.rs"), declarations)
+        .expect("Failed to write day declarations");
+}
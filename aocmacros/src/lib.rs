@@ -0,0 +1,385 @@
+//! Attributes that declare solutions as plain functions. They are meant to be
+//! used through their re-exports in `aocutils`, whose paths the generated
+//! code refers to.
+
+use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Registers a function as one part of a day, so the runner finds it just
+/// like a day registered with `register_day!`.
+///
+/// The function takes the `&Input` and returns anything that becomes an
+/// `Answer`, or a `Result` of it. The title is optional and only needs to be
+/// given for one of the parts.
+///
+/// ```ignore
+/// #[aoc(year = 2023, day = 6, part = 1, title = "Wait For It")]
+/// fn part_one(input: &Input) -> Result<u64> {
+///     ...
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    match registration(args, &item) {
+        Ok(registration) => item.into_iter().chain(registration).collect(),
+        Err(error) => item.into_iter().chain(error.to_compile_error()).collect(),
+    }
+}
+
+/// Turns an example into a test of the function, which is called with the
+/// example input and has to return the expected `answer`.
+///
+/// It can also be put on the unit struct of a day that implements
+/// `Solution`, which then has to solve the example with the answers given as
+/// `part_one` and `part_two`. Either of them may be left out.
+///
+/// The input is either the text itself or a `file` relative to the crate. A
+/// function may have several examples, which become the tests
+/// `<function>_example_1`, `<function>_example_2` and so on. A single one is
+/// just `<function>_example`. The tests of a struct are named after it in
+/// lowercase, e.g. `day6_example`.
+///
+/// ```ignore
+/// #[aoc_example(input = "Time: 7 15 30\nDistance: 9 40 200\n", answer = 288)]
+/// #[aoc_example(file = "inputs/day-6-test.txt", answer = 288)]
+/// fn part_one(input: &Input) -> Result<u64> {
+///     ...
+/// }
+///
+/// #[aoc_example(file = "inputs/day-6-test.txt", part_one = 288, part_two = 71503)]
+/// pub struct Day6;
+/// ```
+#[proc_macro_attribute]
+pub fn aoc_example(args: TokenStream, item: TokenStream) -> TokenStream {
+    // The examples further down are expanded here as well, so the tests are
+    // numbered in the order they are written in.
+    let mut examples = vec![args];
+    let item = take_examples(item, &mut examples);
+    match tests(&examples, &item) {
+        Ok(tests) => item.into_iter().chain(tests).collect(),
+        Err(error) => item.into_iter().chain(error.to_compile_error()).collect(),
+    }
+}
+
+struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    fn new(span: Span, message: impl Into<String>) -> Self {
+        Error {
+            span,
+            message: message.into(),
+        }
+    }
+
+    fn to_compile_error(&self) -> TokenStream {
+        let mut message = TokenTree::Literal(proc_macro::Literal::string(&self.message));
+        message.set_span(self.span);
+        let tokens = [
+            TokenTree::Ident(Ident::new("compile_error", self.span)),
+            TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, message.into())),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        ];
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                token.set_span(self.span);
+                token
+            })
+            .collect()
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// An argument such as `day = 5` with the tokens of its value.
+struct Argument {
+    key: Ident,
+    value: Vec<TokenTree>,
+}
+
+impl Argument {
+    fn text(&self) -> String {
+        self.value.iter().map(ToString::to_string).collect()
+    }
+
+    fn span(&self) -> Span {
+        self.value.first().map_or(self.key.span(), TokenTree::span)
+    }
+
+    fn number(&self, range: std::ops::RangeInclusive<u16>) -> Result<u16> {
+        self.text()
+            .parse()
+            .ok()
+            .filter(|number| range.contains(number))
+            .ok_or_else(|| {
+                let (first, last) = (range.start(), range.end());
+                let message = format!("`{}` has to be a number from {first} to {last}", self.key);
+                Error::new(self.span(), message)
+            })
+    }
+
+    fn string(&self) -> Result<String> {
+        match self.value.as_slice() {
+            [TokenTree::Literal(literal)] if literal.to_string().starts_with(['"', 'r']) => {
+                Ok(literal.to_string())
+            }
+            _ => Err(Error::new(
+                self.span(),
+                format!("`{}` has to be a string", self.key),
+            )),
+        }
+    }
+}
+
+/// Parses `key = value, ...` where a value is everything up to the next comma.
+fn arguments(args: TokenStream) -> Result<Vec<Argument>> {
+    let mut arguments = Vec::new();
+    let mut tokens = args.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let TokenTree::Ident(key) = token else {
+            return Err(Error::new(token.span(), "expected `name = value`"));
+        };
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '=' => (),
+            _ => return Err(Error::new(key.span(), format!("expected `{key} = ...`"))),
+        }
+        let mut value = Vec::new();
+        while let Some(token) = tokens.next_if(|token| !is_punct(token, ',')) {
+            value.push(token);
+        }
+        if value.is_empty() {
+            return Err(Error::new(key.span(), format!("`{key}` has no value")));
+        }
+        tokens.next();
+        arguments.push(Argument { key, value });
+    }
+    Ok(arguments)
+}
+
+fn is_punct(token: &TokenTree, char: char) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == char)
+}
+
+fn unknown_argument(key: &Ident, expected: &str) -> Error {
+    Error::new(
+        key.span(),
+        format!("unknown argument `{key}`, expected {expected}"),
+    )
+}
+
+fn missing_argument(name: &str) -> Error {
+    Error::new(Span::call_site(), format!("missing argument `{name}`"))
+}
+
+/// The name of the function `item`.
+fn function_name(item: &TokenStream) -> Result<Ident> {
+    let mut tokens = item.clone().into_iter();
+    while let Some(token) = tokens.next() {
+        if let TokenTree::Ident(ident) = &token
+            && ident.to_string() == "fn"
+            && let Some(TokenTree::Ident(name)) = tokens.next()
+        {
+            return Ok(name);
+        }
+    }
+    Err(Error::new(Span::call_site(), "expected a function"))
+}
+
+fn registration(args: TokenStream, item: &TokenStream) -> Result<TokenStream> {
+    let (mut year, mut day, mut part, mut title) = (None, None, None, None);
+    for argument in arguments(args)? {
+        match argument.key.to_string().as_str() {
+            "year" => year = Some(argument.number(2015..=9999)?),
+            "day" => day = Some(argument.number(1..=25)?),
+            "part" => part = Some(argument.number(1..=2)?),
+            "title" => title = Some(argument.string()?),
+            _ => {
+                let expected = "`year`, `day`, `part` or `title`";
+                return Err(unknown_argument(&argument.key, expected));
+            }
+        }
+    }
+    let year = year.ok_or_else(|| missing_argument("year"))?;
+    let day = day.ok_or_else(|| missing_argument("day"))?;
+    let part = match part.ok_or_else(|| missing_argument("part"))? {
+        1 => "One",
+        _ => "Two",
+    };
+    let title = title.map_or("None".to_string(), |title| format!("Some({title})"));
+    let name = function_name(item)?;
+
+    let registration = format!(
+        r#"
+        const _: () = {{
+            fn solve(input: &::aocutils::Input) -> ::aocutils::Result<::aocutils::Answer> {{
+                ::aocutils::solution::IntoAnswer::into_answer({name}(input))
+            }}
+
+            ::aocutils::inventory::submit! {{
+                ::aocutils::registry::PartFn {{
+                    year: {year},
+                    day: {day},
+                    part: ::aocutils::Part::{part},
+                    title: {title},
+                    input_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+                    solve,
+                }}
+            }}
+        }};
+        "#
+    );
+    Ok(registration
+        .parse()
+        .expect("the registration is valid Rust"))
+}
+
+/// Removes the `#[aoc_example(...)]` attributes from `item` and adds their
+/// arguments to `examples`.
+fn take_examples(item: TokenStream, examples: &mut Vec<TokenStream>) -> TokenStream {
+    let mut kept = Vec::new();
+    let mut tokens = item.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        if is_punct(&token, '#')
+            && let Some(TokenTree::Group(attribute)) = tokens.peek()
+            && attribute.delimiter() == Delimiter::Bracket
+            && let Some(args) = example_arguments(attribute.stream())
+        {
+            examples.push(args);
+            tokens.next();
+            continue;
+        }
+        kept.push(token);
+    }
+    kept.into_iter().collect()
+}
+
+/// The arguments of an attribute if it is `aoc_example(...)`, whichever path
+/// it is used by.
+fn example_arguments(attribute: TokenStream) -> Option<TokenStream> {
+    let tokens: Vec<_> = attribute.into_iter().collect();
+    match tokens.as_slice() {
+        [.., TokenTree::Ident(name), TokenTree::Group(args)]
+            if name.to_string() == "aoc_example" && args.delimiter() == Delimiter::Parenthesis =>
+        {
+            Some(args.stream())
+        }
+        _ => None,
+    }
+}
+
+/// What an example is a test of.
+enum Target {
+    /// A function that solves one part.
+    Part(Ident),
+    /// The unit struct of a whole day.
+    Day(Ident),
+}
+
+/// The function or struct `item` declares.
+fn target(item: &TokenStream) -> Result<Target> {
+    let mut tokens = item.clone().into_iter();
+    while let Some(token) = tokens.next() {
+        if let TokenTree::Ident(keyword) = &token
+            && let keyword @ ("fn" | "struct") = keyword.to_string().as_str()
+            && let Some(TokenTree::Ident(name)) = tokens.next()
+        {
+            return Ok(match keyword {
+                "fn" => Target::Part(name),
+                _ => Target::Day(name),
+            });
+        }
+    }
+    Err(Error::new(
+        Span::call_site(),
+        "expected a function or the struct of a day",
+    ))
+}
+
+fn tests(examples: &[TokenStream], item: &TokenStream) -> Result<TokenStream> {
+    let target = target(item)?;
+    let prefix = match &target {
+        Target::Part(name) => name.to_string(),
+        Target::Day(name) => name.to_string().to_lowercase(),
+    };
+    let mut tests = String::new();
+    for (index, args) in examples.iter().enumerate() {
+        let (mut input, mut answer, mut part_one, mut part_two) = (None, None, None, None);
+        for argument in arguments(args.clone())? {
+            match argument.key.to_string().as_str() {
+                "input" => input = Some(argument.string()?),
+                "file" => {
+                    let file = argument.string()?;
+                    input = Some(format!(
+                        r#"include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", {file}))"#
+                    ));
+                }
+                "answer" if matches!(target, Target::Part(_)) => answer = Some(argument.text()),
+                "part_one" if matches!(target, Target::Day(_)) => part_one = Some(argument.text()),
+                "part_two" if matches!(target, Target::Day(_)) => part_two = Some(argument.text()),
+                _ => {
+                    let expected = match target {
+                        Target::Part(_) => "`input`, `file` or `answer`",
+                        Target::Day(_) => "`input`, `file`, `part_one` or `part_two`",
+                    };
+                    return Err(unknown_argument(&argument.key, expected));
+                }
+            }
+        }
+        let input = input.ok_or_else(|| missing_argument("input"))?;
+        let test = match examples.len() {
+            1 => format!("{prefix}_example"),
+            _ => format!("{prefix}_example_{}", index + 1),
+        };
+
+        let checks = match &target {
+            Target::Part(name) => {
+                let answer = answer.ok_or_else(|| missing_argument("answer"))?;
+                format!(
+                    "let answer = ::aocutils::solution::IntoAnswer::into_answer({name}(&input));
+                    assert_eq!(answer.unwrap(), ::aocutils::Answer::from({answer}));"
+                )
+            }
+            Target::Day(name) => {
+                if part_one.is_none() && part_two.is_none() {
+                    return Err(missing_argument("part_one` or `part_two"));
+                }
+                // Each part is solved on its own, so an unsolved one is not run.
+                [("One", "part_one", part_one), ("Two", "part_two", part_two)]
+                    .into_iter()
+                    .filter_map(|(part, field, answer)| {
+                        Some(format!(
+                            "let timings = ::aocutils::Solver::solve(
+                                &{name}, &input, Some(::aocutils::Part::{part})
+                            );
+                            assert_eq!(
+                                timings.unwrap().{field}.unwrap().answer,
+                                ::aocutils::Answer::from({})
+                            );",
+                            answer?
+                        ))
+                    })
+                    .collect()
+            }
+        };
+
+        tests += &format!(
+            r#"
+            #[cfg(test)]
+            #[test]
+            fn {test}() {{
+                let input = ::aocutils::Input::new({input});
+                {checks}
+            }}
+            "#
+        );
+    }
+    tests.parse().map_err(|_| {
+        Error::new(
+            Span::call_site(),
+            "the example does not make a valid test, is `answer` a value?",
+        )
+    })
+}
@@ -0,0 +1,138 @@
+use crate::{
+    check_errors, check_input,
+    cli::{BenchArgs, OutputFormat},
+    error::RunnerError,
+    load_input, select_days,
+    summary::format_table,
+};
+use aocutils::{
+    bench::{Stats, bench_day, format_duration},
+    locate::InputLocator,
+};
+use std::fs;
+
+/// The statistics of one step of one day.
+struct Row {
+    year: u16,
+    day: u8,
+    step: &'static str,
+    stats: Stats,
+}
+
+fn to_table(rows: &[Row]) -> String {
+    let rows: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.year.to_string(),
+                row.day.to_string(),
+                row.step.to_string(),
+                row.stats.iterations.to_string(),
+                format_duration(row.stats.min),
+                format_duration(row.stats.median),
+                format_duration(row.stats.mean),
+            ]
+        })
+        .collect();
+    let header = ["Year", "Day", "Step", "Runs", "Min", "Median", "Mean"];
+    format_table(header, &rows)
+}
+
+fn to_json(rows: &[Row]) -> String {
+    let objects: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"step\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}}}",
+                row.year,
+                row.day,
+                row.step,
+                row.stats.iterations,
+                row.stats.min.as_nanos(),
+                row.stats.median.as_nanos(),
+                row.stats.mean.as_nanos()
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+fn to_csv(rows: &[Row]) -> String {
+    let mut csv = "year,day,step,iterations,min_ns,median_ns,mean_ns\n".to_string();
+    for row in rows {
+        csv += &format!(
+            "{},{},{},{},{},{},{}\n",
+            row.year,
+            row.day,
+            row.step,
+            row.stats.iterations,
+            row.stats.min.as_nanos(),
+            row.stats.median.as_nanos(),
+            row.stats.mean.as_nanos()
+        );
+    }
+    csv
+}
+
+/// Benchmarks the selected days and writes the statistics in the requested format.
+pub fn bench_days(locator: &InputLocator, args: &BenchArgs) -> Result<(), RunnerError> {
+    let selection = &args.run.selection;
+    let days = select_days(&selection.days)?;
+    check_input(selection, &days)?;
+
+    let source = selection
+        .source()
+        .unwrap_or_else(|| args.run.mode.clone().into());
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for day in &days {
+        // Progress goes to stderr so the results can be piped.
+        eprintln!(
+            "Benchmarking {} day {} ({}) with the {source} input",
+            day.year, day.day, day.title
+        );
+        let bench = load_input(locator, day, &source, selection)
+            .and_then(|input| bench_day(day, &input, selection.part, args.iterations as usize));
+
+        let bench = match bench {
+            Ok(Some(bench)) => bench,
+            Ok(None) => continue,
+            Err(error) => {
+                eprintln!("Failed: {error}");
+                errors.push(error);
+                continue;
+            }
+        };
+        let steps = [
+            ("parse", Some(bench.parse)),
+            ("part one", bench.part_one),
+            ("part two", bench.part_two),
+        ];
+        for (step, stats) in steps {
+            if let Some(stats) = stats {
+                rows.push(Row {
+                    year: day.year,
+                    day: day.day,
+                    step,
+                    stats,
+                });
+            }
+        }
+    }
+
+    let output = match args.format {
+        OutputFormat::Table => to_table(&rows),
+        OutputFormat::Json => to_json(&rows),
+        OutputFormat::Csv => to_csv(&rows),
+    };
+    match &args.output {
+        Some(path) => fs::write(path, output).map_err(|source| RunnerError::WriteOutput {
+            path: path.clone(),
+            source,
+        })?,
+        None => print!("{output}"),
+    }
+
+    check_errors(errors, days.len())
+}
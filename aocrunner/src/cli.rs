@@ -0,0 +1,200 @@
+use aocutils::{
+    ExecutionMode, Part,
+    source::InputSource,
+    utility::{parse_execution_mode, parse_input_source},
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::{ops::RangeInclusive, path::PathBuf};
+
+/// Runs Advent of Code solutions of every year.
+#[derive(Debug, Parser)]
+#[command(name = "aoc", version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// Look for input files in this directory first. `{year}` is replaced by the year.
+    #[arg(long, global = true)]
+    pub input_dir: Option<PathBuf>,
+
+    /// Name input files like this, e.g. `day{day:02}{suffix}.txt`.
+    #[arg(long, global = true)]
+    pub input_template: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Runs the selected days, with the personal input unless `--mode` says otherwise.
+    Run(RunArgs),
+    /// Runs the selected days with the example input.
+    Test(SelectionArgs),
+    /// Repeatedly runs the selected days and reports how long each step took.
+    Bench(BenchArgs),
+    /// Checks the answers of the selected days against the expected ones.
+    Verify(DaySelection),
+    /// Solves one part with the personal input and submits the answer.
+    Submit(SubmitArgs),
+    /// Downloads the personal inputs of the selected days that are not on disk yet.
+    Download(DaySelection),
+    /// Creates the files of a new day from a template.
+    New(NewArgs),
+    /// Saves the examples of a saved puzzle page and their answers as test inputs.
+    Examples(ExamplesArgs),
+    /// Lists all implemented days.
+    List {
+        /// Only list the days of this year.
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub selection: SelectionArgs,
+
+    /// Which input to use: `normal` or `test`.
+    #[arg(short, long, default_value = "normal", value_parser = parse_execution_mode)]
+    pub mode: ExecutionMode,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub run: RunArgs,
+
+    /// How often to run every day.
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+
+    /// How to write the results.
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Write the results to this file instead of the terminal.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    #[arg(short, long)]
+    pub year: u16,
+
+    #[arg(short, long)]
+    pub day: u8,
+
+    /// The part to submit, `1` or `2`.
+    #[arg(short, long, value_parser = str::parse::<Part>)]
+    pub part: Part,
+}
+
+#[derive(Debug, Args)]
+pub struct NewArgs {
+    #[arg(short, long)]
+    pub year: u16,
+
+    #[arg(short, long)]
+    pub day: u8,
+
+    /// The title of the puzzle, `Day <day>` if omitted.
+    #[arg(short, long)]
+    pub title: Option<String>,
+
+    /// The crate of the year, found from its other days if omitted.
+    #[arg(long)]
+    pub crate_dir: Option<PathBuf>,
+
+    /// Make the solution from this template instead of `day_template` in the config.
+    #[arg(long)]
+    pub template: Option<PathBuf>,
+
+    /// Take the examples and their answers from this saved puzzle page.
+    #[arg(long)]
+    pub puzzle: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct ExamplesArgs {
+    #[arg(short, long)]
+    pub year: u16,
+
+    #[arg(short, long)]
+    pub day: u8,
+
+    /// The puzzle page as saved from the browser.
+    pub puzzle: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Args)]
+pub struct DaySelection {
+    /// Year to run, every year if omitted.
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// Day or inclusive range of days such as `1-7`, every day if omitted.
+    #[arg(short, long, value_parser = parse_days)]
+    pub day: Option<RangeInclusive<u8>>,
+}
+
+#[derive(Debug, Args)]
+pub struct SelectionArgs {
+    #[command(flatten)]
+    pub days: DaySelection,
+
+    /// Only run this part, `1` or `2`.
+    #[arg(short, long, value_parser = str::parse::<Part>)]
+    pub part: Option<Part>,
+
+    /// Read the input from this file, or from stdin if it is `-`, instead of the day's input file.
+    #[arg(short, long, value_parser = parse_input_source)]
+    pub input: Option<InputSource>,
+
+    /// Use this text as the input instead of the day's input file.
+    #[arg(long, conflicts_with = "input")]
+    pub input_text: Option<String>,
+
+    /// Skip the lines that cannot be parsed instead of failing.
+    #[arg(long)]
+    pub lenient: bool,
+}
+
+impl SelectionArgs {
+    /// The input given on the command line, if any.
+    pub fn source(&self) -> Option<InputSource> {
+        match &self.input_text {
+            Some(text) => Some(InputSource::Inline(text.clone())),
+            None => self.input.clone(),
+        }
+    }
+}
+
+fn parse_days(input: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse = |day: &str| {
+        day.parse::<u8>()
+            .map_err(|_| format!("{day:?} is not a valid day."))
+    };
+
+    match input.split_once('-') {
+        Some((first, last)) => {
+            let (first, last) = (parse(first)?, parse(last)?);
+            match first <= last {
+                true => Ok(first..=last),
+                false => Err(format!(
+                    "{input:?} is not a valid range, {first} comes after {last}."
+                )),
+            }
+        }
+        None => {
+            let day = parse(input)?;
+            Ok(day..=day)
+        }
+    }
+}
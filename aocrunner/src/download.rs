@@ -0,0 +1,26 @@
+use crate::{check_errors, error::RunnerError};
+use aocutils::{
+    client::{Client, Download},
+    locate::InputLocator,
+    registry::Day,
+};
+
+/// Downloads the personal input of every day that does not have one yet.
+pub fn download_days(locator: &InputLocator, days: &[&'static Day]) -> Result<(), RunnerError> {
+    let mut client = Client::from_env()?;
+    let mut errors = Vec::new();
+
+    for day in days {
+        let name = format!("{} day {}", day.year, day.day);
+        match client.download(locator, day) {
+            Ok(Download::Cached(path)) => println!("{name}: already at {}", path.display()),
+            Ok(Download::Downloaded(path)) => println!("{name}: saved to {}", path.display()),
+            Err(error) => {
+                println!("{name}: {error}");
+                errors.push(error);
+            }
+        }
+    }
+
+    check_errors(errors, days.len())
+}
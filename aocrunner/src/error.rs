@@ -0,0 +1,113 @@
+use aocutils::submit::{Check, Outcome};
+use std::{fmt, io, path::PathBuf, process::ExitCode};
+
+/// Everything that can make the runner fail after its arguments were parsed.
+#[derive(Debug)]
+pub enum RunnerError {
+    /// A single day or the day selection failed.
+    Aoc(aocutils::Error),
+    /// No registered day matches the selection.
+    NothingSelected { year: Option<u16>, days: String },
+    /// `--input` or `--input-text` was given for more than one day.
+    AmbiguousInput { selected: usize },
+    /// Some of the selected days could not be run.
+    DaysFailed { failed: usize, total: usize },
+    /// Some answers differ from the expected ones, or were never given
+    /// because their day failed.
+    VerificationFailed { wrong: usize, unverified: usize },
+    /// The results could not be written to the output file.
+    WriteOutput { path: PathBuf, source: io::Error },
+    /// There is no day of the year to find its crate from.
+    UnknownCrate { year: u16 },
+    /// The part to submit has no answer.
+    Unsolved,
+    /// The ledger says that submitting the answer is pointless.
+    SubmissionRefused { answer: String, check: Check },
+    /// The submitted answer was not the right one, or was not checked at all.
+    NotAccepted { outcome: Outcome },
+}
+
+impl RunnerError {
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            RunnerError::DaysFailed { .. } => ExitCode::from(1),
+            RunnerError::Aoc(error) => match error {
+                // Exit code 2 is what clap uses for usage errors.
+                aocutils::Error::UnknownMode(_) => ExitCode::from(2),
+                aocutils::Error::UnknownDay { .. } => ExitCode::from(3),
+                aocutils::Error::MissingInput { .. }
+                | aocutils::Error::UnreadableLine { .. }
+                | aocutils::Error::NoInputFound { .. }
+                | aocutils::Error::Io { .. } => ExitCode::from(5),
+                aocutils::Error::InvalidConfig { .. }
+                | aocutils::Error::InvalidLedger { .. }
+                | aocutils::Error::MissingSession => ExitCode::from(9),
+                aocutils::Error::Http { .. } => ExitCode::from(10),
+                aocutils::Error::Parse { .. } => ExitCode::from(6),
+                aocutils::Error::AlreadyExists { .. } => ExitCode::from(13),
+            },
+            RunnerError::NothingSelected { .. } | RunnerError::UnknownCrate { .. } => {
+                ExitCode::from(3)
+            }
+            RunnerError::AmbiguousInput { .. } => ExitCode::from(4),
+            RunnerError::VerificationFailed { .. } => ExitCode::from(7),
+            RunnerError::WriteOutput { .. } => ExitCode::from(8),
+            RunnerError::Unsolved | RunnerError::SubmissionRefused { .. } => ExitCode::from(11),
+            RunnerError::NotAccepted { .. } => ExitCode::from(12),
+        }
+    }
+}
+
+impl fmt::Display for RunnerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunnerError::Aoc(error) => write!(f, "{error}"),
+            RunnerError::NothingSelected { year, days } => {
+                let year = year.map_or("any year".to_string(), |year| year.to_string());
+                write!(f, "no implemented day matches day {days} of {year}")
+            }
+            RunnerError::AmbiguousInput { selected } => write!(
+                f,
+                "--input and --input-text need exactly one day to be selected, but {selected} are"
+            ),
+            RunnerError::DaysFailed { failed, total } => {
+                write!(f, "{failed} of {total} days failed")
+            }
+            RunnerError::VerificationFailed { wrong, unverified } => match (wrong, unverified) {
+                (wrong, 0) => write!(f, "{wrong} answers did not match the expected answers"),
+                (0, unverified) => write!(f, "{unverified} answers could not be verified"),
+                (wrong, unverified) => write!(
+                    f,
+                    "{wrong} answers did not match the expected answers and {unverified} could not be verified"
+                ),
+            },
+            RunnerError::WriteOutput { path, source } => {
+                write!(f, "unable to write {}: {source}", path.display())
+            }
+            RunnerError::UnknownCrate { year } => {
+                write!(f, "no day of {year} exists yet, pass --crate-dir")
+            }
+            RunnerError::Unsolved => write!(f, "the part has no answer to submit"),
+            RunnerError::SubmissionRefused { answer, check } => {
+                write!(f, "not submitting {answer}: {check}")
+            }
+            RunnerError::NotAccepted { outcome } => write!(f, "the answer was {outcome}"),
+        }
+    }
+}
+
+impl std::error::Error for RunnerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RunnerError::Aoc(error) => error.source(),
+            RunnerError::WriteOutput { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<aocutils::Error> for RunnerError {
+    fn from(error: aocutils::Error) -> Self {
+        RunnerError::Aoc(error)
+    }
+}
@@ -0,0 +1,252 @@
+use aocutils::{
+    ExecutionMode, Input, TimedAnswer, Timings,
+    bench::format_duration,
+    locate::InputLocator,
+    registry::{self, Day},
+    source::InputSource,
+};
+use clap::Parser;
+use std::process::ExitCode;
+
+// Linking the year crates is what puts their days into the registry.
+use adventofcode as _;
+use adventofcode2025 as _;
+
+pub mod bench;
+pub mod cli;
+pub mod download;
+pub mod error;
+pub mod new;
+pub mod submit;
+pub mod summary;
+pub mod verify;
+
+use cli::{Cli, Command, DaySelection, SelectionArgs};
+use error::RunnerError;
+use summary::{DayResult, print_summary};
+
+pub fn run() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = locator(&cli).and_then(|locator| match cli.command {
+        Command::Run(args) => run_days(&locator, &args.selection, args.mode),
+        Command::Test(selection) => run_days(&locator, &selection, ExecutionMode::Test),
+        Command::Bench(args) => bench::bench_days(&locator, &args),
+        Command::Verify(selection) => {
+            select_days(&selection).and_then(|days| verify::verify_days(&locator, &days))
+        }
+        Command::Submit(args) => submit::submit_answer(&locator, &args),
+        Command::Download(selection) => {
+            select_days(&selection).and_then(|days| download::download_days(&locator, &days))
+        }
+        Command::New(args) => new::new_day(&locator, &args),
+        Command::Examples(args) => new::save_examples(&locator, &args),
+        Command::List { year } => {
+            list_days(year);
+            Ok(())
+        }
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            error.exit_code()
+        }
+    }
+}
+
+/// The input locator with the command line options taking precedence.
+fn locator(cli: &Cli) -> Result<InputLocator, RunnerError> {
+    let mut locator = InputLocator::from_env()?;
+    if let Some(template) = &cli.input_template {
+        locator = locator.with_template(template);
+    }
+    if let Some(dir) = &cli.input_dir {
+        locator = locator.with_dir(dir.to_string_lossy());
+    }
+    Ok(locator)
+}
+
+fn list_days(year: Option<u16>) {
+    for day in registry::days() {
+        if year.is_none_or(|year| day.year == year) {
+            println!("{} day {:>2}: {}", day.year, day.day, day.title);
+        }
+    }
+}
+
+fn select_days(selection: &DaySelection) -> Result<Vec<&'static Day>, RunnerError> {
+    let (year, days) = (selection.year, &selection.day);
+    if let (Some(year), Some(days)) = (year, days)
+        && days.start() == days.end()
+    {
+        return Ok(vec![registry::get(year, *days.start())?]);
+    }
+
+    let selected: Vec<_> = registry::days()
+        .into_iter()
+        .filter(|day| year.is_none_or(|year| day.year == year))
+        .filter(|day| days.as_ref().is_none_or(|days| days.contains(&day.day)))
+        .collect();
+
+    if selected.is_empty() {
+        let days = match days {
+            Some(days) if days.start() == days.end() => days.start().to_string(),
+            Some(days) => format!("{}-{}", days.start(), days.end()),
+            None => "any".to_string(),
+        };
+        return Err(RunnerError::NothingSelected { year, days });
+    }
+
+    Ok(selected)
+}
+
+fn check_input(selection: &SelectionArgs, days: &[&'static Day]) -> Result<(), RunnerError> {
+    match days.len() {
+        selected if selected > 1 && selection.source().is_some() => {
+            Err(RunnerError::AmbiguousInput { selected })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The inputs `day` runs with: test mode runs every example there is.
+fn modes_of(locator: &InputLocator, day: &Day, mode: &ExecutionMode) -> Vec<ExecutionMode> {
+    match mode {
+        ExecutionMode::Test => {
+            let examples = locator.examples(day);
+            // Without any example, running the default one reports where we looked.
+            match examples.is_empty() {
+                true => vec![ExecutionMode::Test],
+                false => examples,
+            }
+        }
+        mode => vec![mode.clone()],
+    }
+}
+
+/// Turns the errors of the selected days into the error of the whole run.
+fn check_errors(mut errors: Vec<aocutils::Error>, total: usize) -> Result<(), RunnerError> {
+    match errors.len() {
+        0 => Ok(()),
+        // A single failing day is reported with its own error.
+        1 if total == 1 => Err(errors.remove(0).into()),
+        failed => Err(RunnerError::DaysFailed { failed, total }),
+    }
+}
+
+fn run_days(
+    locator: &InputLocator,
+    selection: &SelectionArgs,
+    mode: ExecutionMode,
+) -> Result<(), RunnerError> {
+    let days = select_days(&selection.days)?;
+    check_input(selection, &days)?;
+    // A run of several days leaves out the ones without an input yet, like
+    // the days of a new year on a fresh checkout.
+    let skip_missing = days.len() > 1;
+
+    let results: Vec<_> = days
+        .iter()
+        .flat_map(|day| {
+            let sources = match selection.source() {
+                Some(source) => vec![source],
+                None => modes_of(locator, day, &mode)
+                    .into_iter()
+                    .map(InputSource::from)
+                    .collect(),
+            };
+            sources
+                .into_iter()
+                .map(|source| execute_day(locator, day, source, selection, skip_missing))
+        })
+        .collect();
+    print_summary(&results);
+
+    let (skipped, results): (Vec<_>, Vec<_>) = results
+        .into_iter()
+        .partition(|result| skip_missing && result.is_missing());
+    if !skipped.is_empty() {
+        println!(
+            "Skipped {} of the days, which have no input yet",
+            skipped.len()
+        );
+    }
+    let total = results.len();
+    let errors = results
+        .into_iter()
+        .filter_map(|result| result.outcome.err())
+        .collect();
+    check_errors(errors, total)
+}
+
+/// Loads the input of `day` from `source`, as strict as the selection asks for.
+fn load_input(
+    locator: &InputLocator,
+    day: &Day,
+    source: &InputSource,
+    selection: &SelectionArgs,
+) -> aocutils::Result<Input> {
+    let input = source.load(locator, day)?;
+    Ok(input.with_strict(!selection.lenient))
+}
+
+fn solve(day: &Day, input: &Input, selection: &SelectionArgs) -> aocutils::Result<Timings> {
+    let timings = day.solution.solve(input, selection.part)?;
+
+    println!("Parsed the input in {}", format_duration(timings.parse));
+    for (name, timed) in [("one", &timings.part_one), ("two", &timings.part_two)] {
+        if let Some(TimedAnswer { answer, duration }) = timed {
+            println!(
+                "The answer for part {name} is {answer} ({})",
+                format_duration(*duration)
+            );
+        }
+    }
+
+    Ok(timings)
+}
+
+fn execute_day(
+    locator: &InputLocator,
+    day: &'static Day,
+    source: InputSource,
+    selection: &SelectionArgs,
+    skip_missing: bool,
+) -> DayResult {
+    println!(
+        "Running {} day {} ({}) with the {source} input",
+        day.year, day.day, day.title
+    );
+    let (outcome, excerpt) = match load_input(locator, day, &source, selection) {
+        Ok(input) => {
+            let outcome = solve(day, &input, selection);
+            for skipped in input.skipped() {
+                println!("Skipped: {skipped}");
+            }
+            let excerpt = outcome
+                .as_ref()
+                .err()
+                .and_then(|error| input.excerpt(error));
+            (outcome, excerpt)
+        }
+        Err(error) => (Err(error), None),
+    };
+    if let Err(error) = &outcome {
+        let verb = match skip_missing && matches!(error, aocutils::Error::NoInputFound { .. }) {
+            true => "Skipped",
+            false => "Failed",
+        };
+        println!("{verb}: {error}");
+        // Parse errors show the line they are about.
+        if let Some(excerpt) = excerpt {
+            println!("{excerpt}");
+        }
+    }
+    DayResult {
+        day,
+        source,
+        outcome,
+    }
+}
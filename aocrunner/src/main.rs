@@ -0,0 +1,6 @@
+use aocrunner::run;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    run()
+}
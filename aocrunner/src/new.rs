@@ -0,0 +1,61 @@
+use crate::{
+    cli::{ExamplesArgs, NewArgs},
+    error::RunnerError,
+};
+use aocutils::{
+    config::Config, locate::InputLocator, puzzle::load_examples, registry, scaffold::Scaffold,
+};
+use std::path::{Path, PathBuf};
+
+/// Creates the solution and input files of a new day.
+pub fn new_day(locator: &InputLocator, args: &NewArgs) -> Result<(), RunnerError> {
+    let mut scaffold = Scaffold::new(args.year, args.day);
+    if let Some(title) = &args.title {
+        scaffold = scaffold.with_title(title);
+    }
+    scaffold = match &args.template {
+        Some(path) => scaffold.with_template_file(path)?,
+        None => scaffold.with_config(&Config::load()?)?,
+    };
+    if let Some(puzzle) = &args.puzzle {
+        scaffold = scaffold.with_examples(load_examples(puzzle)?);
+    }
+
+    let crate_dir = match &args.crate_dir {
+        Some(dir) => dir.clone(),
+        None => crate_dir(args.year)?,
+    };
+    for path in scaffold.create(&crate_dir, locator)? {
+        println!("Created {}", path.display());
+    }
+    Ok(())
+}
+
+/// Saves the examples of a puzzle page next to the inputs of an existing day.
+pub fn save_examples(locator: &InputLocator, args: &ExamplesArgs) -> Result<(), RunnerError> {
+    let day = registry::get(args.year, args.day)?;
+    let examples = load_examples(&args.puzzle)?;
+    if examples.is_empty() {
+        println!("No examples found in {}", args.puzzle.display());
+    }
+    for example in examples {
+        let path = example.save(locator, day)?;
+        let answers = example.answers.to_string();
+        let answers = match answers.is_empty() {
+            true => "no answers".to_string(),
+            false => answers.trim_end().replace('\n', ", "),
+        };
+        println!("Saved {} ({answers}) to {}", example.mode, path.display());
+    }
+    Ok(())
+}
+
+/// The crate the other days of `year` are in.
+fn crate_dir(year: u16) -> Result<PathBuf, RunnerError> {
+    registry::days()
+        .into_iter()
+        .find(|day| day.year == year)
+        .and_then(|day| Path::new(day.input_dir).parent())
+        .map(Path::to_path_buf)
+        .ok_or(RunnerError::UnknownCrate { year })
+}
@@ -0,0 +1,48 @@
+use crate::{cli::SubmitArgs, error::RunnerError};
+use aocutils::{
+    Answer, ExecutionMode, Part, TimedAnswer,
+    client::Client,
+    locate::InputLocator,
+    registry,
+    submit::{Check, Entry, Ledger, Outcome},
+};
+
+/// Solves a part with the personal input and submits the answer, unless the
+/// ledger already knows it to be wrong.
+pub fn submit_answer(locator: &InputLocator, args: &SubmitArgs) -> Result<(), RunnerError> {
+    let day = registry::get(args.year, args.day)?;
+    let input = locator.load(day, &ExecutionMode::Normal)?;
+    let timings = day.solution.solve(&input, Some(args.part))?;
+    let solved = match args.part {
+        Part::One => timings.part_one,
+        Part::Two => timings.part_two,
+    };
+    let answer = match solved {
+        Some(TimedAnswer { answer, .. }) if answer != Answer::Unsolved => answer.to_string(),
+        _ => return Err(RunnerError::Unsolved),
+    };
+
+    let mut ledger = Ledger::load(Ledger::path(locator, day))?;
+    let check = ledger.check(day.day, args.part, &answer);
+    if check != Check::Submit {
+        return Err(RunnerError::SubmissionRefused { answer, check });
+    }
+
+    println!(
+        "Submitting {answer} for part {} of {} day {}",
+        args.part, day.year, day.day
+    );
+    let reply = Client::from_env()?.submit(day.year, day.day, args.part, &answer)?;
+    println!("{}", reply.message);
+    ledger.record(Entry {
+        day: day.day,
+        part: args.part,
+        outcome: reply.outcome,
+        answer,
+    })?;
+
+    match reply.outcome {
+        Outcome::Correct => Ok(()),
+        outcome => Err(RunnerError::NotAccepted { outcome }),
+    }
+}
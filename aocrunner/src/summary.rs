@@ -0,0 +1,89 @@
+use aocutils::{Timings, bench::format_duration, registry::Day, source::InputSource};
+
+/// What running a single day with one of its inputs produced.
+pub struct DayResult {
+    pub day: &'static Day,
+    pub source: InputSource,
+    pub outcome: aocutils::Result<Timings>,
+}
+
+impl DayResult {
+    /// Whether the day could not run because its input is not there yet.
+    pub fn is_missing(&self) -> bool {
+        matches!(self.outcome, Err(aocutils::Error::NoInputFound { .. }))
+    }
+
+    fn columns(&self) -> [String; 9] {
+        let [part_one, part_two, parse, time_one, time_two] = match &self.outcome {
+            Ok(timings) => {
+                let [(part_one, time_one), (part_two, time_two)] =
+                    [&timings.part_one, &timings.part_two].map(|timed| match timed {
+                        Some(timed) => (timed.answer.to_string(), format_duration(timed.duration)),
+                        None => ("-".to_string(), "-".to_string()),
+                    });
+                let parse = format_duration(timings.parse);
+                [part_one, part_two, parse, time_one, time_two]
+            }
+            Err(aocutils::Error::NoInputFound { .. }) => {
+                ["missing", "missing", "-", "-", "-"].map(String::from)
+            }
+            Err(_) => ["error", "error", "-", "-", "-"].map(String::from),
+        };
+        [
+            self.day.year.to_string(),
+            self.day.day.to_string(),
+            self.day.title.to_string(),
+            self.source.to_string(),
+            part_one,
+            part_two,
+            parse,
+            time_one,
+            time_two,
+        ]
+    }
+}
+
+/// Prints one row per day with both answers and their timings, aligned in columns.
+pub fn print_summary(results: &[DayResult]) {
+    let rows: Vec<_> = results.iter().map(DayResult::columns).collect();
+    let header = [
+        "Year", "Day", "Title", "Input", "Part one", "Part two", "Parse", "Time one", "Time two",
+    ];
+    print_table(header, &rows);
+}
+
+/// Prints `rows` below `header` with every column padded to its widest cell.
+pub fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    println!();
+    print!("{}", format_table(header, rows));
+}
+
+/// Formats `rows` below `header` with every column padded to its widest cell.
+pub fn format_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let header = header.map(String::from);
+
+    let mut widths = header.clone().map(|column| column.len());
+    for row in rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    let format_row = |row: &[String; N]| {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(column, width)| format!("{column:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        format!("{}\n", line.trim_end())
+    };
+
+    let mut table = format_row(&header);
+    table += &widths.map(|width| "-".repeat(width)).join("-+-");
+    table += "\n";
+    for row in rows {
+        table += &format_row(row);
+    }
+    table
+}
@@ -0,0 +1,55 @@
+use crate::{error::RunnerError, modes_of, summary::print_table};
+use aocutils::{
+    ExecutionMode,
+    answers::{Verdict, verify_day},
+    locate::InputLocator,
+    registry::Day,
+};
+
+fn describe(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Pass => "pass".to_string(),
+        Verdict::Fail { expected, actual } => format!("FAIL (expected {expected}, got {actual})"),
+        Verdict::Missing => "missing".to_string(),
+    }
+}
+
+/// Verifies the personal and every example input of every day and prints the verdicts.
+pub fn verify_days(locator: &InputLocator, days: &[&'static Day]) -> Result<(), RunnerError> {
+    let mut rows = Vec::new();
+    let (mut wrong, mut unverified) = (0, 0);
+
+    for day in days {
+        let examples = modes_of(locator, day, &ExecutionMode::Test);
+        for mode in [ExecutionMode::Normal].into_iter().chain(examples) {
+            let [part_one, part_two] = match verify_day(locator, day, &mode) {
+                Ok(verdicts) => {
+                    wrong += verdicts
+                        .iter()
+                        .filter(|verdict| matches!(verdict, Verdict::Fail { .. }))
+                        .count();
+                    verdicts.each_ref().map(describe)
+                }
+                Err(error) => {
+                    unverified += 2;
+                    let error = format!("error: {error}");
+                    [error.clone(), error]
+                }
+            };
+            rows.push([
+                day.year.to_string(),
+                day.day.to_string(),
+                mode.to_string(),
+                part_one,
+                part_two,
+            ]);
+        }
+    }
+
+    print_table(["Year", "Day", "Input", "Part one", "Part two"], &rows);
+
+    match (wrong, unverified) {
+        (0, 0) => Ok(()),
+        (wrong, unverified) => Err(RunnerError::VerificationFailed { wrong, unverified }),
+    }
+}
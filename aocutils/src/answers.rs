@@ -0,0 +1,158 @@
+use crate::{
+    Answer, Error, ExecutionMode, Result, TimedAnswer, locate::InputLocator, registry::Day,
+    utility::read_input,
+};
+use std::{
+    fmt, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+/// The answers we know to be correct for one input of a day.
+///
+/// They live next to the input as `day-N.answers` or `day-N-test.answers`,
+/// with one `<part>: <answer>` line per known part.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl fmt::Display for ExpectedAnswers {
+    /// Writes the answers the way [`parse_expected_answers`] reads them.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer) in [("1", &self.part_one), ("2", &self.part_two)] {
+            if let Some(answer) = answer {
+                writeln!(f, "{part}: {answer}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How an answer compares to the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Answer,
+    },
+    /// There is no expected answer (or no input) to compare against.
+    Missing,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, actual: &Answer) -> Self {
+        match expected {
+            None => Verdict::Missing,
+            Some(expected) if *actual != Answer::Unsolved && actual.to_string() == expected => {
+                Verdict::Pass
+            }
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual: actual.clone(),
+            },
+        }
+    }
+}
+
+/// The path of the expected answers for the input at `input_path`.
+pub fn answers_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("answers")
+}
+
+pub fn parse_expected_answers(text: &str) -> Result<ExpectedAnswers> {
+    let mut answers = ExpectedAnswers::default();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (part, answer) = line
+            .split_once(':')
+            .ok_or_else(|| Error::parse(index + 1, "expected `<part>: <answer>`"))?;
+        let answer = Some(answer.trim().to_string());
+        match part.trim() {
+            "1" => answers.part_one = answer,
+            "2" => answers.part_two = answer,
+            part => return Err(Error::parse(index + 1, format!("unknown part {part:?}"))),
+        }
+    }
+    Ok(answers)
+}
+
+/// Loads the expected answers for the input at `input_path`, which are empty
+/// if there is no answers file.
+pub fn load_expected_answers(input_path: &Path) -> Result<ExpectedAnswers> {
+    let path = answers_path(input_path);
+    match fs::read_to_string(&path) {
+        Ok(text) => parse_expected_answers(&text),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(ExpectedAnswers::default()),
+        Err(source) => Err(Error::Io { path, source }),
+    }
+}
+
+/// Solves both parts of `day` and compares them to the expected answers.
+///
+/// A day without an input file is [`Verdict::Missing`] for both parts.
+pub fn verify_day(locator: &InputLocator, day: &Day, mode: &ExecutionMode) -> Result<[Verdict; 2]> {
+    let path = match locator.locate(day, mode) {
+        Ok(path) => path,
+        Err(Error::NoInputFound { .. }) => return Ok([Verdict::Missing, Verdict::Missing]),
+        Err(error) => return Err(error),
+    };
+    let input = read_input(&path)?;
+    let expected = load_expected_answers(&path)?;
+    let timings = day.solution.solve(&input, None)?;
+
+    let check = |expected: &Option<String>, actual: Option<TimedAnswer>| {
+        let actual = actual.map_or(Answer::Unsolved, |timed| timed.answer);
+        Verdict::check(expected.as_deref(), &actual)
+    };
+    Ok([
+        check(&expected.part_one, timings.part_one),
+        check(&expected.part_two, timings.part_two),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = parse_expected_answers("1: 35\n\n2: abc\n").unwrap();
+        assert_eq!(answers.part_one.as_deref(), Some("35"));
+        assert_eq!(answers.part_two.as_deref(), Some("abc"));
+
+        assert_eq!(
+            parse_expected_answers(&answers.to_string()).unwrap(),
+            answers
+        );
+
+        let answers = parse_expected_answers("2: 281\n").unwrap();
+        assert_eq!(answers.part_one, None);
+        assert_eq!(answers.to_string(), "2: 281\n");
+
+        assert!(parse_expected_answers("3: 1\n").is_err());
+        assert!(parse_expected_answers("35\n").is_err());
+    }
+
+    #[test]
+    fn check_verdict() {
+        assert_eq!(
+            Verdict::check(Some("35"), &Answer::Number(35)),
+            Verdict::Pass
+        );
+        assert_eq!(Verdict::check(None, &Answer::Number(35)), Verdict::Missing);
+        assert!(matches!(
+            Verdict::check(Some("35"), &Answer::Number(36)),
+            Verdict::Fail { .. }
+        ));
+        assert!(matches!(
+            Verdict::check(Some("unsolved"), &Answer::Unsolved),
+            Verdict::Fail { .. }
+        ));
+    }
+}
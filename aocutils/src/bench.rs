@@ -0,0 +1,105 @@
+use crate::{Input, Part, Result, registry::Day};
+use std::time::Duration;
+
+/// Summary statistics of repeated measurements of one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Summarizes the samples, or returns `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() {
+            0 => return None,
+            len if len % 2 == 0 => (sorted[middle - 1] + sorted[middle]) / 2,
+            _ => sorted[middle],
+        };
+
+        Some(Stats {
+            iterations: sorted.len(),
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / sorted.len() as u32,
+        })
+    }
+}
+
+/// The statistics of parsing and solving one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub parse: Stats,
+    pub part_one: Option<Stats>,
+    pub part_two: Option<Stats>,
+}
+
+/// Parses `input` and solves `part` (or both) `iterations` times.
+pub fn bench_day(
+    day: &Day,
+    input: &Input,
+    part: Option<Part>,
+    iterations: usize,
+) -> Result<Option<DayBench>> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part_one = Vec::with_capacity(iterations);
+    let mut part_two = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let timings = day.solution.solve(input, part)?;
+        parse.push(timings.parse);
+        part_one.extend(timings.part_one.map(|timed| timed.duration));
+        part_two.extend(timings.part_two.map(|timed| timed.duration));
+    }
+
+    Ok(Stats::from_samples(&parse).map(|parse| DayBench {
+        parse,
+        part_one: Stats::from_samples(&part_one),
+        part_two: Stats::from_samples(&part_two),
+    }))
+}
+
+/// Formats a duration with a unit that keeps the number short, e.g. `12.34ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{nanos}ns"),
+        1_000..1_000_000 => format!("{:.2}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", duration.as_secs_f64()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [3, 1, 4, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+
+        let stats = Stats::from_samples(&samples[..3]).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25s");
+    }
+}
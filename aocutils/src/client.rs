@@ -0,0 +1,356 @@
+use crate::{
+    Error, ExecutionMode, Part, Result,
+    config::Config,
+    locate::InputLocator,
+    registry::Day,
+    submit::{Reply, parse_reply, reply_text},
+};
+use std::{
+    env, fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Talks to the Advent of Code website to fetch inputs and submit answers.
+///
+/// The session token is the `session` cookie of a logged in browser. It and
+/// the base URL come from the `AOC_SESSION` and `AOC_BASE_URL` environment
+/// variables, or else from `session` and `base_url` in the [`Config`]. The
+/// token is only needed once a request is made.
+/// Requests are spaced at least [`Client::DEFAULT_DELAY`] apart so a
+/// whole year can be fetched without hammering the server.
+#[derive(Debug)]
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    delay: Duration,
+    agent: ureq::Agent,
+    last_request: Option<Instant>,
+}
+
+/// Where an input is and whether it had to be downloaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Download {
+    /// The input was already on disk and has not been requested.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Client {
+    pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+    pub const DEFAULT_DELAY: Duration = Duration::from_secs(2);
+
+    pub fn new(session: impl Into<String>) -> Self {
+        Self::with_session(Some(session.into()))
+    }
+
+    fn with_session(session: Option<String>) -> Self {
+        Client {
+            base_url: Self::DEFAULT_BASE_URL.to_string(),
+            session,
+            delay: Self::DEFAULT_DELAY,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aocutils/", env!("CARGO_PKG_VERSION")))
+                .timeout(Duration::from_secs(30))
+                .build(),
+            last_request: None,
+        }
+    }
+
+    /// A client configured from the environment and the config file.
+    pub fn from_env() -> Result<Self> {
+        Self::from_sources(&Config::load()?, env::vars())
+    }
+
+    pub fn from_sources(
+        config: &Config,
+        env: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self> {
+        let (mut session, mut base_url) = (None, None);
+        for (key, value) in env {
+            match key.as_str() {
+                "AOC_SESSION" => session = Some(value),
+                "AOC_BASE_URL" => base_url = Some(value),
+                _ => (),
+            }
+        }
+
+        let session = session
+            .or_else(|| config.get("session").map(String::from))
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+        let mut client = Client::with_session(session);
+        if let Some(base_url) = base_url.or_else(|| config.get("base_url").map(String::from)) {
+            client = client.with_base_url(base_url);
+        }
+        Ok(client)
+    }
+
+    /// Sends requests to `base_url` instead, e.g. to a local stand-in server.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Waits at least `delay` between two requests.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+
+    /// Requests the personal input of a day, whether or not it is on disk.
+    pub fn fetch(&mut self, year: u16, day: u8) -> Result<String> {
+        let url = self.input_url(year, day);
+        self.send("GET", url, None)
+    }
+
+    pub fn answer_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}/answer", self.base_url)
+    }
+
+    /// Submits `answer` for `part` of a day and reads the reply.
+    pub fn submit(&mut self, year: u16, day: u8, part: Part, answer: &str) -> Result<Reply> {
+        let url = self.answer_url(year, day);
+        let level = part.name();
+        let form = [("level", level), ("answer", answer)];
+        let html = self.send("POST", url.clone(), Some(&form))?;
+        parse_reply(&html).ok_or_else(|| Error::Http {
+            url,
+            message: format!("unexpected reply: {}", reply_text(&html)),
+        })
+    }
+
+    /// Sends a `method` request to `url` with the session cookie and the
+    /// `form`, if any, and returns the body of the response.
+    fn send(&mut self, method: &str, url: String, form: Option<&[(&str, &str)]>) -> Result<String> {
+        let session = self.session.as_ref().ok_or(Error::MissingSession)?;
+        self.throttle();
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={session}"));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        self.last_request = Some(Instant::now());
+
+        let error = |message: String| Error::Http {
+            url: url.clone(),
+            message,
+        };
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|source| error(source.to_string())),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(error(format!("{status} {}", body.trim())))
+            }
+            Err(ureq::Error::Transport(transport)) => {
+                // Its own message would repeat the URL.
+                let mut message = transport.kind().to_string();
+                if let Some(source) = std::error::Error::source(&transport) {
+                    message += &format!(": {source}");
+                }
+                Err(error(message))
+            }
+        }
+    }
+
+    /// Makes sure the personal input of `day` is where `locator` looks for it.
+    ///
+    /// An input found by `locator` is never downloaded again, unless it is
+    /// empty like the one a new day starts with. Otherwise it is written to
+    /// the first place `locator` would look.
+    pub fn download(&mut self, locator: &InputLocator, day: &Day) -> Result<Download> {
+        let mode = ExecutionMode::Normal;
+        let path = match locator.locate(day, &mode) {
+            Ok(path) if fs::metadata(&path).is_ok_and(|file| file.len() > 0) => {
+                return Ok(Download::Cached(path));
+            }
+            Ok(path) => path,
+            Err(Error::NoInputFound { .. }) => locator
+                .candidates(day, &mode)
+                .into_iter()
+                .next()
+                .expect("there is always at least one candidate"),
+            Err(error) => return Err(error),
+        };
+
+        let input = self.fetch(day.year, day.day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| Error::Io {
+                path: dir.to_path_buf(),
+                source,
+            })?;
+        }
+        fs::write(&path, input).map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(Download::Downloaded(path))
+    }
+
+    fn throttle(&self) {
+        if let Some(last) = self.last_request {
+            thread::sleep(self.delay.saturating_sub(last.elapsed()));
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// A stand-in for the website that answers `responses` requests with
+    /// `status` and `body`. It returns the request line, the cookie and the
+    /// body of every request it got.
+    pub(crate) fn serve(
+        responses: usize,
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for stream in listener.incoming().take(responses) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    let lowercase = line.to_ascii_lowercase();
+                    if let Some(value) = lowercase.strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if !line.contains(':') || lowercase.starts_with("cookie") {
+                        request += line;
+                        request += "\n";
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request += &String::from_utf8(content).unwrap();
+                requests.push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, server)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stand_in::serve, *};
+    use crate::registry::tests::dummy_day;
+
+    const DAY: Day = dummy_day(5, "crate/inputs");
+
+    #[test]
+    fn fetch_input() {
+        let (url, server) = serve(1, "200 OK", "1 2 3\n");
+        let mut client = Client::new("abc").with_base_url(url);
+        assert_eq!(client.fetch(2023, 5).unwrap(), "1 2 3\n");
+        assert_eq!(
+            server.join().unwrap(),
+            ["GET /2023/day/5/input HTTP/1.1\nCookie: session=abc\n"]
+        );
+    }
+
+    #[test]
+    fn report_status() {
+        let (url, server) = serve(1, "404 Not Found", "Not yet!\n");
+        let mut client = Client::new("abc").with_base_url(url);
+        let error = client.fetch(2023, 25).unwrap_err();
+        server.join().unwrap();
+        assert!(matches!(&error, Error::Http { message, .. } if message == "404 Not yet!"));
+    }
+
+    #[test]
+    fn download_once() {
+        let dir = env::temp_dir().join(format!("aocutils-download-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let locator = InputLocator::default().with_dir(dir.join("{year}").to_string_lossy());
+
+        let (url, server) = serve(1, "200 OK", "seeds: 1\n");
+        let mut client = Client::new("abc")
+            .with_base_url(url)
+            .with_delay(Duration::from_millis(50));
+        let path = dir.join("2023").join("day-5.txt");
+        // An empty input is only a placeholder.
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+        assert_eq!(
+            client.download(&locator, &DAY).unwrap(),
+            Download::Downloaded(path.clone())
+        );
+        let start = Instant::now();
+        assert_eq!(
+            client.download(&locator, &DAY).unwrap(),
+            Download::Cached(path.clone())
+        );
+        // Nothing was requested, so there was nothing to wait for.
+        assert!(start.elapsed() < Duration::from_millis(50));
+        assert_eq!(server.join().unwrap().len(), 1);
+        assert_eq!(
+            locator.load(&DAY, &ExecutionMode::Normal).unwrap().raw(),
+            "seeds: 1\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn throttle_requests() {
+        let (url, server) = serve(2, "200 OK", "");
+        let mut client = Client::new("abc")
+            .with_base_url(url)
+            .with_delay(Duration::from_millis(100));
+        let start = Instant::now();
+        client.fetch(2023, 1).unwrap();
+        client.fetch(2023, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn session_sources() {
+        let config =
+            Config::parse("session = from-config\nbase_url = http://localhost/\n").unwrap();
+        let env = |session: &str| vec![("AOC_SESSION".to_string(), session.to_string())];
+
+        let client = Client::from_sources(&config, env("from-env")).unwrap();
+        assert_eq!(client.session.as_deref(), Some("from-env"));
+        assert_eq!(
+            client.input_url(2023, 1),
+            "http://localhost/2023/day/1/input"
+        );
+        let client = Client::from_sources(&config, []).unwrap();
+        assert_eq!(client.session.as_deref(), Some("from-config"));
+
+        let mut client = Client::from_sources(&Config::default(), env(" ")).unwrap();
+        assert!(matches!(client.fetch(2023, 1), Err(Error::MissingSession)));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{Error, Result};
+use std::{collections::HashMap, env, fs, io::ErrorKind, path::PathBuf};
+
+/// Settings from the `aoc.conf` file.
+///
+/// The file holds one `key = value` pair per line, `#` starts a comment and
+/// values may be quoted:
+///
+/// ```text
+/// # Look for inputs in a folder per year.
+/// input_dir = inputs/{year}
+/// input_dir.2023 = 2023/inputs
+/// input_template = day{day:02}{suffix}.txt
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    values: HashMap<String, String>,
+}
+
+impl Config {
+    /// The environment variable that points to a different config file.
+    pub const PATH_VARIABLE: &str = "AOC_CONFIG";
+    pub const FILE_NAME: &str = "aoc.conf";
+
+    /// Loads the file named by `AOC_CONFIG`, or `aoc.conf` in the current
+    /// directory. A missing `aoc.conf` yields an empty config.
+    pub fn load() -> Result<Self> {
+        match env::var_os(Self::PATH_VARIABLE) {
+            Some(path) => Self::from_file(PathBuf::from(path)),
+            None => match Self::from_file(PathBuf::from(Self::FILE_NAME)) {
+                Err(Error::Io { source, .. }) if source.kind() == ErrorKind::NotFound => {
+                    Ok(Config::default())
+                }
+                config => config,
+            },
+        }
+    }
+
+    pub fn from_file(path: PathBuf) -> Result<Self> {
+        let text = fs::read_to_string(&path).map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?;
+        Self::parse(&text).map_err(|error| match error {
+            Error::Parse { line, message, .. } => Error::InvalidConfig {
+                path,
+                line,
+                message,
+            },
+            error => error,
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut values = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::parse(index + 1, "expected `key = value`"))?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+            values.insert(key.trim().to_string(), value.to_string());
+        }
+        Ok(Config { values })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// All entries, in no particular order.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config = Config::parse(
+            "# comment\n\ninput_dir = inputs/{year} # trailing\ninput_template = \"day{day:02}.txt\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.get("input_dir"), Some("inputs/{year}"));
+        assert_eq!(config.get("input_template"), Some("day{day:02}.txt"));
+        assert_eq!(config.get("missing"), None);
+
+        assert!(matches!(
+            Config::parse("input_dir\n"),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+}
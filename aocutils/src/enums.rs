@@ -0,0 +1,152 @@
+use std::fmt;
+
+/// Declares an enum whose variants are parsed from and displayed as names.
+///
+/// Every variant lists its name and any other names it is parsed from. The
+/// enum derives `Ord` in the order of declaration and gets `ALL`, `index`,
+/// `name`, `names`, `FromStr`, `TryFrom<char>` and `Display`.
+///
+/// ```
+/// aocutils::named_enum! {
+///     pub enum Digit {
+///         One => "1" | "one",
+///         Two => "2" | "two",
+///     }
+/// }
+///
+/// assert_eq!("two".parse(), Ok(Digit::Two));
+/// assert_eq!(Digit::try_from('1'), Ok(Digit::One));
+/// assert_eq!(Digit::Two.to_string(), "2");
+/// assert!(Digit::One < Digit::Two);
+/// assert_eq!(Digit::ALL.map(Digit::index), [0, 1]);
+/// ```
+#[macro_export]
+macro_rules! named_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $first:literal $(| $other:literal)*
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Every variant in the order of declaration.
+            $vis const ALL: [$name; [$($name::$variant),+].len()] = [$($name::$variant),+];
+
+            /// The position of the variant in `ALL`.
+            $vis const fn index(self) -> usize {
+                self as usize
+            }
+
+            /// The name the variant is displayed as.
+            $vis const fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => $first),+
+                }
+            }
+
+            /// Every name the variant is parsed from.
+            $vis const fn names(self) -> &'static [&'static str] {
+                match self {
+                    $($name::$variant => &[$first $(, $other)*]),+
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::enums::UnknownName;
+
+            fn from_str(name: &str) -> ::std::result::Result<Self, Self::Err> {
+                match name {
+                    $($first $(| $other)* => Ok($name::$variant),)+
+                    _ => Err($crate::enums::UnknownName {
+                        type_name: stringify!($name),
+                        names: &[$($first $(, $other)*),+],
+                    }),
+                }
+            }
+        }
+
+        impl ::std::convert::TryFrom<char> for $name {
+            type Error = $crate::enums::UnknownName;
+
+            fn try_from(name: char) -> ::std::result::Result<Self, Self::Error> {
+                name.encode_utf8(&mut [0; 4]).parse()
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.pad(self.name())
+            }
+        }
+    };
+}
+
+/// The error of parsing a [`named_enum!`] from something that is none of its names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownName {
+    pub type_name: &'static str,
+    /// Every name of every variant.
+    pub names: &'static [&'static str],
+}
+
+impl fmt::Display for UnknownName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a {}, one of ", self.type_name)?;
+        for (index, name) in self.names.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnknownName {}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::Line;
+
+    named_enum! {
+        /// The colors of 2023 day 2.
+        enum Color {
+            Red => "red" | "r",
+            Green => "green",
+            Blue => "blue",
+        }
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!("r".parse(), Ok(Color::Red));
+        assert_eq!(Color::Red.names(), ["red", "r"]);
+        assert_eq!(format!("{:>6}|{}", Color::Green, Color::Red), " green|red");
+        assert_eq!(Color::ALL, [Color::Red, Color::Green, Color::Blue]);
+        assert_eq!(Color::Blue.index(), 2);
+        assert!(Color::Blue > Color::Green);
+        assert!(Color::try_from('r').is_ok());
+        assert!(Color::try_from('x').is_err());
+
+        let error = Line::new("7 purple", 3)
+            .split_once(" ")
+            .and_then(|(_, color)| color.parse::<Color>())
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "failed to parse line 3, column 3: invalid value \"purple\": \
+             expected a Color, one of \"red\", \"r\", \"green\", \"blue\""
+        );
+    }
+}
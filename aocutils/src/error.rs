@@ -0,0 +1,147 @@
+use std::{fmt, io, path::PathBuf};
+
+/// Everything that can go wrong while loading inputs and running days.
+#[derive(Debug)]
+pub enum Error {
+    /// The input file does not exist or cannot be opened.
+    MissingInput { path: PathBuf, source: io::Error },
+    /// A line of the input file could not be read.
+    UnreadableLine {
+        path: PathBuf,
+        line: usize,
+        source: io::Error,
+    },
+    /// None of the places we look for an input contains it.
+    NoInputFound { tried: Vec<PathBuf> },
+    /// Reading or writing a file other than an input failed.
+    Io { path: PathBuf, source: io::Error },
+    /// A file that is about to be created is already there.
+    AlreadyExists { path: PathBuf },
+    /// A line of the config file is malformed.
+    InvalidConfig {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+    /// A line of the ledger of submitted answers is malformed.
+    InvalidLedger {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+    /// The execution mode is not one we know.
+    UnknownMode(String),
+    /// No solution is registered for the day.
+    UnknownDay { year: u16, day: u8 },
+    /// Downloading needs a session token, but none is configured.
+    MissingSession,
+    /// A request to the Advent of Code website failed.
+    Http { url: String, message: String },
+    /// The input could not be parsed. Lines and columns are counted from 1.
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn parse(line: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn parse_at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column: Some(column),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput { path, source } => {
+                write!(f, "unable to open input file {}: {source}", path.display())
+            }
+            Error::UnreadableLine { path, line, source } => {
+                write!(
+                    f,
+                    "unable to read line {line} of {}: {source}",
+                    path.display()
+                )
+            }
+            Error::NoInputFound { tried } => {
+                write!(f, "no input found, tried:")?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::AlreadyExists { path } => {
+                write!(f, "{} already exists, not overwriting it", path.display())
+            }
+            Error::InvalidConfig {
+                path,
+                line,
+                message,
+            } => write!(
+                f,
+                "invalid config {} line {line}: {message}",
+                path.display()
+            ),
+            Error::InvalidLedger {
+                path,
+                line,
+                message,
+            } => write!(
+                f,
+                "invalid ledger {} line {line}: {message}",
+                path.display()
+            ),
+            Error::UnknownMode(mode) => {
+                write!(
+                    f,
+                    "unknown execution mode {mode:?}, expected \"normal\", \"test\" or \"test-<name>\""
+                )
+            }
+            Error::UnknownDay { year, day } => {
+                write!(f, "day {day} of {year} does not (yet) exist")
+            }
+            Error::MissingSession => write!(
+                f,
+                "no session token, set AOC_SESSION or `session` in the config file"
+            ),
+            Error::Http { url, message } => write!(f, "request to {url} failed: {message}"),
+            Error::Parse {
+                line,
+                column: None,
+                message,
+            } => write!(f, "failed to parse line {line}: {message}"),
+            Error::Parse {
+                line,
+                column: Some(column),
+                message,
+            } => write!(f, "failed to parse line {line}, column {column}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::MissingInput { source, .. }
+            | Error::UnreadableLine { source, .. }
+            | Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
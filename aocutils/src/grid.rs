@@ -0,0 +1,293 @@
+use crate::{
+    Error, Input, Result,
+    point::{Direction, Point},
+};
+use std::ops::{Index, IndexMut, Range};
+
+/// A rectangle of cells, addressed by [`Point`]s with the origin at the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// Cells next to each other in one row, e.g. the digits of a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub y: usize,
+    pub x: Range<usize>,
+}
+
+impl Span {
+    pub fn positions(&self) -> impl Iterator<Item = Point> + use<> {
+        let y = self.y;
+        self.x.clone().map(move |x| Point { x, y })
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns made of `cells` in row order.
+    ///
+    /// Panics if the cells do not fill whole rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = match cells.len() {
+            0 => 0,
+            len => {
+                assert!(
+                    width > 0 && len.is_multiple_of(width),
+                    "{len} cells do not fill rows of {width}"
+                );
+                len / width
+            }
+        };
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses every character of the input into a cell, reporting the line
+    /// and column of the first one `cell` rejects.
+    pub fn parse(input: &Input, mut cell: impl FnMut(u8) -> Option<T>) -> Result<Self> {
+        let chars = input.grid()?;
+        let mut cells = Vec::with_capacity(chars.width() * chars.height());
+        for (y, row) in chars.rows().enumerate() {
+            for (x, &char) in row.iter().enumerate() {
+                let parsed = cell(char).ok_or_else(|| {
+                    Error::parse_at(y + 1, x + 1, format!("unexpected {:?}", char as char))
+                })?;
+                cells.push(parsed);
+            }
+        }
+        Ok(Grid {
+            cells,
+            width: chars.width(),
+            height: chars.height(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Point) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    /// The index of `position` in the cells, if it is inside the grid.
+    pub fn index_of(&self, position: Point) -> Option<usize> {
+        match position.y < self.height {
+            true => position.to_index(self.width),
+            false => None,
+        }
+    }
+
+    /// The position of the cell at `index`, if there is one.
+    pub fn point_of(&self, index: usize) -> Option<Point> {
+        match index < self.cells.len() {
+            true => Point::from_index(index, self.width),
+            false => None,
+        }
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        position: Point,
+        directions: [Direction; N],
+    ) -> impl Iterator<Item = Point> + use<T, N> {
+        let (width, height) = (self.width, self.height);
+        directions.into_iter().filter_map(move |direction| {
+            let neighbour = position.step(direction)?;
+            (neighbour.x < width && neighbour.y < height).then_some(neighbour)
+        })
+    }
+
+    /// The positions north, east, south and west of `position` that are
+    /// inside the grid.
+    pub fn neighbours4(&self, position: Point) -> impl Iterator<Item = Point> + use<T> {
+        self.neighbours(position, Direction::CARDINAL)
+    }
+
+    /// Like [`Grid::neighbours4`], but with the diagonals.
+    pub fn neighbours8(&self, position: Point) -> impl Iterator<Item = Point> + use<T> {
+        self.neighbours(position, Direction::ALL)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a width of 0, which only empty grids have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(|x| self.column(x))
+    }
+
+    /// The positions of all cells matching `predicate`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.positions()
+            .filter(move |position| predicate(&self[*position]))
+    }
+
+    /// The longest horizontal runs of cells matching `predicate`, row by row.
+    pub fn spans<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Span> + 'a {
+        let mut spans = Vec::new();
+        for (y, row) in self.rows().enumerate() {
+            let mut start = None;
+            for (x, cell) in row.iter().enumerate() {
+                match (predicate(cell), start) {
+                    (true, None) => start = Some(x),
+                    (false, Some(first)) => {
+                        spans.push(Span { y, x: first..x });
+                        start = None;
+                    }
+                    _ => (),
+                }
+            }
+            if let Some(first) = start {
+                spans.push(Span {
+                    y,
+                    x: first..self.width,
+                });
+            }
+        }
+        spans.into_iter()
+    }
+
+    /// A grid of the same size with every cell mapped.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u8> {
+        Grid::parse(&Input::new("467..114\n...*....\n..35..63\n"), Some).unwrap()
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (8, 3));
+        assert_eq!(grid[Point::new(3, 1)], b'*');
+        assert_eq!(grid.get(Point::new(8, 0)), None);
+        assert_eq!(grid.index_of(Point::new(3, 1)), Some(11));
+        assert_eq!(grid.index_of(Point::new(0, 3)), None);
+        assert_eq!(grid.point_of(11), Some(Point::new(3, 1)));
+        assert_eq!(grid.point_of(24), None);
+        assert_eq!(grid.row(2), Some(&b"..35..63"[..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.column(3).unwrap().copied().collect::<Vec<_>>(), b".*5");
+        assert!(grid.column(8).is_none());
+        assert_eq!(grid.columns().count(), 8);
+        assert_eq!(Grid::from_cells(2, vec![1, 2, 3, 4]).height(), 2);
+
+        let error = Grid::parse(&Input::new("12\n3x\n"), |c| (c as char).to_digit(10)).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 2,
+                column: Some(2),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = example();
+        let corner: Vec<_> = grid.neighbours4(Point::ORIGIN).collect();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(3, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Point::new(7, 2)).count(), 3);
+    }
+
+    #[test]
+    fn find_and_spans() {
+        let grid = example();
+        let symbols: Vec<_> = grid.find_all(|c| *c == b'*').collect();
+        assert_eq!(symbols, [Point::new(3, 1)]);
+
+        let spans: Vec<_> = grid.spans(u8::is_ascii_digit).collect();
+        assert_eq!(
+            spans,
+            [
+                Span { y: 0, x: 0..3 },
+                Span { y: 0, x: 5..8 },
+                Span { y: 2, x: 2..4 },
+                Span { y: 2, x: 6..8 },
+            ]
+        );
+        assert_eq!(
+            spans[2].positions().collect::<Vec<_>>(),
+            [Point::new(2, 2), Point::new(3, 2)]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{Error, Result, parse::Line};
+use std::sync::Mutex;
+
+/// The puzzle input, read once and handed out as borrowed views.
+///
+/// Windows line endings are turned into `\n` when the input is created, so
+/// every view only ever sees `\n`.
+///
+/// Inputs are strict unless told otherwise: a line that cannot be parsed is
+/// an error rather than being skipped.
+#[derive(Debug)]
+pub struct Input {
+    text: String,
+    strict: bool,
+    /// Why each skipped line could not be parsed, with an excerpt of it.
+    skipped: Mutex<Vec<String>>,
+}
+
+impl Clone for Input {
+    fn clone(&self) -> Self {
+        Input {
+            text: self.text.clone(),
+            strict: self.strict,
+            skipped: Mutex::new(self.skipped()),
+        }
+    }
+}
+
+/// Inputs are the same if they have the same text and strictness, whatever
+/// they skipped so far.
+impl PartialEq for Input {
+    fn eq(&self, other: &Self) -> bool {
+        (&self.text, self.strict) == (&other.text, other.strict)
+    }
+}
+
+impl Eq for Input {}
+
+impl Input {
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let text = match text.contains('\r') {
+            true => text.replace("\r\n", "\n"),
+            false => text,
+        };
+        Input {
+            text,
+            strict: true,
+            skipped: Mutex::default(),
+        }
+    }
+
+    /// The same input, but skipping the lines that cannot be parsed if
+    /// `strict` is `false`.
+    pub fn with_strict(self, strict: bool) -> Self {
+        Input { strict, ..self }
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// The lines a lenient input skipped so far, each with the error it
+    /// caused and an excerpt of it.
+    pub fn skipped(&self) -> Vec<String> {
+        self.skipped
+            .lock()
+            .expect("no parser panics while holding it")
+            .clone()
+    }
+
+    /// The whole input as it was read.
+    pub fn raw(&self) -> &str {
+        &self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    /// The lines along with their numbers, for parsers that report where
+    /// they fail.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.lines()
+            .enumerate()
+            .map(|(index, text)| Line::new(text, index + 1))
+    }
+
+    /// The line at `index`, counted from 0, or a parse error if there is none.
+    pub fn line(&self, index: usize) -> Result<Line<'_>> {
+        self.numbered_lines()
+            .nth(index)
+            .ok_or_else(|| Error::parse(index + 1, "missing line"))
+    }
+
+    /// The blocks of lines separated by blank lines, without their line breaks
+    /// at either end.
+    pub fn paragraphs(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .split("\n\n")
+            .map(|paragraph| paragraph.trim_matches('\n'))
+            .filter(|paragraph| !paragraph.is_empty())
+    }
+
+    /// Parses every line, see [`Input::parse_all`].
+    pub fn parse_lines<T>(&self, parse: impl FnMut(Line<'_>) -> Result<T>) -> Result<Vec<T>> {
+        self.parse_all(self.numbered_lines(), parse)
+    }
+
+    /// Parses each of `lines`. A strict input fails with the error of the
+    /// first line that cannot be parsed, any other input skips those lines
+    /// and keeps them for [`Input::skipped`].
+    pub fn parse_all<'a, T>(
+        &self,
+        lines: impl IntoIterator<Item = Line<'a>>,
+        mut parse: impl FnMut(Line<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let parsed = lines.into_iter().map(&mut parse);
+        if self.strict {
+            return parsed.collect();
+        }
+
+        let mut values = Vec::new();
+        let mut skipped = Vec::new();
+        for result in parsed {
+            match result {
+                Ok(value) => values.push(value),
+                Err(error) => skipped.push(match self.excerpt(&error) {
+                    Some(excerpt) => format!("{error}\n{excerpt}"),
+                    None => error.to_string(),
+                }),
+            }
+        }
+        self.skipped
+            .lock()
+            .expect("no parser panics while holding it")
+            .extend(skipped);
+        Ok(values)
+    }
+
+    /// The line a parse error points at, with a marker under its column.
+    pub fn excerpt(&self, error: &Error) -> Option<String> {
+        let Error::Parse { line, column, .. } = error else {
+            return None;
+        };
+        let text = self.lines().nth(line.checked_sub(1)?)?;
+        let gutter = " ".repeat(line.to_string().len());
+        let mut excerpt = format!("{line} | {text}");
+        if let Some(column) = column {
+            excerpt += &format!("\n{gutter} | {}^", " ".repeat(column.saturating_sub(1)));
+        }
+        Some(excerpt)
+    }
+
+    /// Like [`Input::paragraphs`], but as numbered lines.
+    pub fn paragraph_lines(&self) -> impl Iterator<Item = Vec<Line<'_>>> {
+        let mut paragraphs = vec![Vec::new()];
+        for line in self.numbered_lines() {
+            match line.is_empty() {
+                true => paragraphs.push(Vec::new()),
+                false => paragraphs.last_mut().unwrap().push(line),
+            }
+        }
+        paragraphs
+            .into_iter()
+            .filter(|paragraph| !paragraph.is_empty())
+    }
+
+    /// The input as a rectangle of characters, one row per line.
+    pub fn grid(&self) -> Result<CharGrid<'_>> {
+        CharGrid::new(&self.text)
+    }
+}
+
+impl Default for Input {
+    fn default() -> Self {
+        Input::new("")
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Input::new(text)
+    }
+}
+
+/// A rectangular view of the bytes of an input without copying them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharGrid<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+}
+
+impl<'a> CharGrid<'a> {
+    fn new(text: &'a str) -> Result<Self> {
+        let text = text.trim_end_matches('\n');
+        let width = text.find('\n').unwrap_or(text.len());
+        for (index, line) in text.lines().enumerate() {
+            if line.len() != width {
+                return Err(Error::parse(
+                    index + 1,
+                    format!("expected {width} columns, found {}", line.len()),
+                ));
+            }
+        }
+
+        Ok(CharGrid {
+            bytes: text.as_bytes(),
+            width,
+            height: text.lines().count(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The byte in column `x` of row `y`, if it is inside the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        // Every row is followed by its line break.
+        Some(self.bytes[y * (self.width + 1) + x])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&'a [u8]> {
+        let start = y * (self.width + 1);
+        (y < self.height).then(|| &self.bytes[start..start + self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + use<'a> {
+        let grid = *self;
+        (0..self.height).filter_map(move |y| grid.row(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_and_paragraphs() {
+        let input =
+            Input::new("seeds: 1 2\r\n\r\na map:\r\n1 2 3\r\n4 5 6\r\n\r\n\r\nb map:\r\n7 8 9\r\n");
+        assert_eq!(input.line(0).unwrap().as_str(), "seeds: 1 2");
+        assert_eq!(input.lines().count(), 9);
+        assert!(matches!(input.line(9), Err(Error::Parse { line: 10, .. })));
+
+        let paragraphs: Vec<_> = input.paragraphs().collect();
+        assert_eq!(
+            paragraphs,
+            ["seeds: 1 2", "a map:\n1 2 3\n4 5 6", "b map:\n7 8 9"]
+        );
+        let numbers: Vec<Vec<_>> = input
+            .paragraph_lines()
+            .map(|lines| lines.iter().map(Line::number).collect())
+            .collect();
+        assert_eq!(numbers, [vec![1], vec![3, 4, 5], vec![8, 9]]);
+    }
+
+    #[test]
+    fn strictness() {
+        let input = Input::new("1\n2\nx\n4\n");
+        let error = input.parse_lines(|line| line.parse::<u32>()).unwrap_err();
+        assert!(matches!(error, Error::Parse { line: 3, .. }));
+        assert_eq!(input.excerpt(&error).unwrap(), "3 | x\n  | ^");
+
+        let input = input.with_strict(false);
+        assert_eq!(
+            input.parse_lines(|line| line.parse::<u32>()).unwrap(),
+            [1, 2, 4]
+        );
+        assert_eq!(input.skipped().len(), 1);
+        assert!(input.skipped()[0].ends_with("\n3 | x\n  | ^"));
+        assert_eq!(input.excerpt(&Error::parse(5, "missing line")), None);
+    }
+
+    #[test]
+    fn grid() {
+        let input = Input::new("467.\n...*\n.35.\n");
+        let grid = input.grid().unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.get(3, 1), Some(b'*'));
+        assert_eq!(grid.get(4, 1), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [b"467.", b"...*", b".35."]);
+
+        assert!(matches!(
+            Input::new("467.\n..*\n").grid(),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert_eq!(Input::new("").grid().unwrap().height(), 0);
+    }
+}
@@ -0,0 +1,316 @@
+use std::ops::Range;
+
+/// The overlap of two ranges, if they have one.
+pub fn intersect<T: Copy + Ord>(a: &Range<T>, b: &Range<T>) -> Option<Range<T>> {
+    let overlap = a.start.max(b.start)..a.end.min(b.end);
+    (!overlap.is_empty()).then_some(overlap)
+}
+
+/// A set of values stored as sorted, disjoint half-open ranges.
+///
+/// Touching ranges are merged, so two sets with the same values are equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// The largest value in the set is the one just below this.
+    pub fn end(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // Everything from `first` to `last` overlaps or touches `range`.
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+        let merged = match first < last {
+            true => {
+                range.start.min(self.ranges[first].start)..range.end.max(self.ranges[last - 1].end)
+            }
+            false => range,
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            ranges.extend(intersect(a, b));
+            // The range that ends first cannot overlap anything else.
+            match a.end <= b.end {
+                true => left.next(),
+                false => right.next(),
+            };
+        }
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            for cut in other.ranges.iter().filter_map(|cut| intersect(range, cut)) {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// The values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let (mut below, mut above) = (Vec::new(), Vec::new());
+        for range in &self.ranges {
+            match range {
+                range if range.end <= at => below.push(range.clone()),
+                range if range.start >= at => above.push(range.clone()),
+                range => {
+                    below.push(range.start..at);
+                    above.push(at..range.end);
+                }
+            }
+        }
+        (RangeSet { ranges: below }, RangeSet { ranges: above })
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// A function that adds an offset to the values of each of its ranges and
+/// keeps every other value as it is.
+///
+/// The ranges never overlap, as inserting one that would is refused. Values
+/// are limited to `i64::MIN..i64::MAX`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OffsetMap {
+    /// Sorted by start.
+    pieces: Vec<(Range<i64>, i64)>,
+}
+
+impl OffsetMap {
+    /// The map that keeps every value.
+    pub fn identity() -> Self {
+        Self::default()
+    }
+
+    /// Maps the values of `range` to `range.start + offset..range.end + offset`.
+    ///
+    /// If some of the values are mapped already, nothing changes and the
+    /// range they are in is returned.
+    pub fn insert(&mut self, range: Range<i64>, offset: i64) -> Result<(), Range<i64>> {
+        if range.is_empty() {
+            return Ok(());
+        }
+        let index = self
+            .pieces
+            .partition_point(|(other, _)| other.start < range.start);
+        // Only the pieces on either side of `index` can overlap `range`.
+        let mut neighbours = self.pieces[index.saturating_sub(1)..].iter().take(2);
+        if let Some((other, _)) = neighbours.find(|(other, _)| intersect(other, &range).is_some()) {
+            return Err(other.clone());
+        }
+        self.pieces.insert(index, (range, offset));
+        Ok(())
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        let index = self.pieces.partition_point(|(range, _)| range.end <= value);
+        match self.pieces.get(index) {
+            Some((range, offset)) if range.contains(&value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Every range of the domain with the offset it gets, gaps included.
+    fn segments(&self) -> Vec<(Range<i64>, i64)> {
+        let mut segments = Vec::new();
+        let mut start = i64::MIN;
+        for (range, offset) in &self.pieces {
+            if start < range.start {
+                segments.push((start..range.start, 0));
+            }
+            segments.push((range.clone(), *offset));
+            start = range.end;
+        }
+        if start < i64::MAX {
+            segments.push((start..i64::MAX, 0));
+        }
+        segments
+    }
+
+    /// The image of all values in `set`.
+    pub fn map_set(&self, set: &RangeSet<i64>) -> RangeSet<i64> {
+        let mut image = RangeSet::new();
+        for range in set.ranges() {
+            for (segment, offset) in self.segments() {
+                if let Some(overlap) = intersect(range, &segment) {
+                    image.insert(overlap.start + offset..overlap.end + offset);
+                }
+            }
+        }
+        image
+    }
+
+    /// The map that applies `self` and then `next`.
+    pub fn then(&self, next: &OffsetMap) -> OffsetMap {
+        let mut composed = OffsetMap::identity();
+        for (segment, offset) in self.segments() {
+            let image = segment.start + offset..segment.end + offset;
+            for (next_segment, next_offset) in next.segments() {
+                if let Some(overlap) = intersect(&image, &next_segment) {
+                    composed.push(
+                        overlap.start - offset..overlap.end - offset,
+                        offset + next_offset,
+                    );
+                }
+            }
+        }
+        composed
+    }
+
+    /// Appends a piece that starts after every other one, skipping offsets of 0.
+    fn push(&mut self, range: Range<i64>, offset: i64) {
+        if offset != 0 {
+            self.pieces.push((range, offset));
+        }
+    }
+}
+
+#[cfg(test)]
+// Single ranges are exactly what the sets are expected to contain.
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> RangeSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let set = set(&[5..8, 1..3, 3..4, 10..12, 7..10, 20..20]);
+        assert_eq!(set.ranges(), [1..4, 5..12]);
+        assert!(set.contains(11));
+        assert!(!set.contains(4));
+        assert_eq!(set.min(), Some(1));
+        assert_eq!(set.end(), Some(12));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25]);
+        assert_eq!(a.union(&b).ranges(), [0..30]);
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..30]);
+        assert_eq!(b.difference(&a).ranges(), [10..20]);
+
+        let (below, above) = a.split_at(25);
+        assert_eq!(below.ranges(), [0..10, 20..25]);
+        assert_eq!(above.ranges(), [25..30]);
+    }
+
+    /// The seed-to-soil and soil-to-fertilizer maps of 2023 day 5.
+    fn almanac_maps() -> (OffsetMap, OffsetMap) {
+        let mut soil = OffsetMap::identity();
+        soil.insert(98..100, 50 - 98).unwrap();
+        soil.insert(50..98, 52 - 50).unwrap();
+        let mut fertilizer = OffsetMap::identity();
+        fertilizer.insert(15..52, -15).unwrap();
+        fertilizer.insert(52..54, 37 - 52).unwrap();
+        fertilizer.insert(0..15, 39).unwrap();
+        (soil, fertilizer)
+    }
+
+    #[test]
+    fn offset_map() {
+        let (soil, fertilizer) = almanac_maps();
+        assert_eq!(
+            [79, 14, 55, 13, 99].map(|seed| soil.map(seed)),
+            [81, 14, 57, 13, 51]
+        );
+
+        let mut overlapping = soil.clone();
+        assert_eq!(overlapping.insert(90..99, 1), Err(50..98));
+        assert_eq!(overlapping.insert(99..120, 1), Err(98..100));
+        assert_eq!(overlapping, soil);
+
+        let image = soil.map_set(&set(&[79..93, 96..100]));
+        assert_eq!(image.ranges(), [50..52, 81..95, 98..100]);
+
+        let both = soil.then(&fertilizer);
+        for seed in -5..110 {
+            assert_eq!(
+                both.map(seed),
+                fertilizer.map(soil.map(seed)),
+                "seed {seed}"
+            );
+        }
+        let seeds = set(&[0..60, 90..110]);
+        assert_eq!(
+            both.map_set(&seeds),
+            fertilizer.map_set(&soil.map_set(&seeds))
+        );
+    }
+}
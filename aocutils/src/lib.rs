@@ -1,28 +1,76 @@
-use crate::utility::read_input;
+use crate::{locate::InputLocator, registry::Day};
+use std::fmt;
 
+pub mod answers;
+pub mod bench;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod config;
+pub mod enums;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod locate;
+pub mod math;
+pub mod parse;
+pub mod point;
+pub mod puzzle;
+pub mod registry;
+pub mod scaffold;
+pub mod solution;
+pub mod source;
+pub mod submit;
 pub mod utility;
 
-pub fn load_input(day: &String, mode: &ExecutionMode) -> Vec<String> {
-    let suffix = match mode {
-        ExecutionMode::Normal => "",
-        ExecutionMode::Test => "-test",
-    };
+pub use aocmacros::{aoc, aoc_example};
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use input::Input;
+pub use parse::Line;
+pub use point::{Direction, Point, Vec2};
+pub use solution::{Answer, Part, Solution, Solver, TimedAnswer, Timings};
 
-    let input_path = format!("inputs/day-{day}{suffix}.txt");
-    let input = read_input(input_path);
+#[doc(hidden)]
+pub use inventory;
 
-    if input.is_err() {
-        let error = input.err().unwrap();
-        panic!("Failed to read input: {error}");
-    }
+// The code generated by `aoc` refers to `::aocutils`, also in our own tests.
+#[cfg(test)]
+extern crate self as aocutils;
 
-    input.unwrap()
+/// Loads the input of `day` from wherever the environment and config say it is.
+pub fn load_input(day: &Day, mode: &ExecutionMode) -> Result<Input> {
+    InputLocator::from_env()?.load(day, mode)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExecutionMode {
     /// Runs the task with our personalized input.
     Normal,
     /// Runs the task with the official test input.
     Test,
+    /// Runs the task with a further named example, e.g. `day-1-test-2.txt`
+    /// for a puzzle whose second part comes with its own example.
+    Example(String),
+}
+
+impl ExecutionMode {
+    /// What the input file name carries after the day.
+    pub fn suffix(&self) -> String {
+        match self {
+            ExecutionMode::Normal => String::new(),
+            ExecutionMode::Test => "-test".to_string(),
+            ExecutionMode::Example(name) => format!("-test-{name}"),
+        }
+    }
+}
+
+impl fmt::Display for ExecutionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionMode::Normal => write!(f, "normal"),
+            ExecutionMode::Test => write!(f, "test"),
+            ExecutionMode::Example(name) => write!(f, "test-{name}"),
+        }
+    }
 }
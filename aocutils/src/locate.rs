@@ -0,0 +1,266 @@
+use crate::{
+    Error, ExecutionMode, Input, Result, config::Config, registry::Day, utility::read_input,
+};
+use std::{env, fs, path::PathBuf};
+
+/// Finds the input file of a day.
+///
+/// Directories may contain `{year}`, templates may contain `{year}`, `{day}`,
+/// `{day:02}` (zero-padded) and `{suffix}` (`-test` for the example input and
+/// `-test-<name>` for further named examples).
+/// Every directory is tried with every template, in the order of precedence:
+/// `with_*` overrides, then the `AOC_INPUT_DIR_<year>`, `AOC_INPUT_DIR` and
+/// `AOC_INPUT_TEMPLATE` environment variables, then `input_dir.<year>`,
+/// `input_dir` and `input_template` from the [`Config`], and finally the
+/// `inputs` directory of the crate that registered the day with
+/// [`InputLocator::DEFAULT_TEMPLATE`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InputLocator {
+    /// Directories and the year they are restricted to, if any.
+    dirs: Vec<(Option<u16>, String)>,
+    templates: Vec<String>,
+}
+
+impl InputLocator {
+    pub const DEFAULT_TEMPLATE: &str = "day-{day}{suffix}.txt";
+
+    /// A locator configured from the environment and the config file.
+    pub fn from_env() -> Result<Self> {
+        Ok(Self::from_sources(&Config::load()?, env::vars()))
+    }
+
+    pub fn from_sources(config: &Config, env: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut locator = InputLocator::default();
+
+        let mut year_dirs = Vec::new();
+        let mut dirs = Vec::new();
+        let mut templates = Vec::new();
+        for (key, value) in env {
+            if let Some(year) = key.strip_prefix("AOC_INPUT_DIR_") {
+                year_dirs.extend(year.parse::<u16>().ok().map(|year| (Some(year), value)));
+            } else if key == "AOC_INPUT_DIR" {
+                dirs.push((None, value));
+            } else if key == "AOC_INPUT_TEMPLATE" {
+                templates.push(value);
+            }
+        }
+        locator.dirs.extend(year_dirs.into_iter().chain(dirs));
+        locator.templates.extend(templates);
+
+        let mut config_dirs: Vec<_> = config
+            .entries()
+            .filter_map(|(key, value)| {
+                let year = key.strip_prefix("input_dir.")?.parse::<u16>().ok()?;
+                Some((Some(year), value.to_string()))
+            })
+            .collect();
+        config_dirs.sort();
+        locator.dirs.extend(config_dirs);
+        locator
+            .dirs
+            .extend(config.get("input_dir").map(|dir| (None, dir.to_string())));
+        locator
+            .templates
+            .extend(config.get("input_template").map(String::from));
+
+        locator
+    }
+
+    /// Looks in `dir` before any other directory.
+    pub fn with_dir(mut self, dir: impl Into<String>) -> Self {
+        self.dirs.insert(0, (None, dir.into()));
+        self
+    }
+
+    /// Tries `template` before any other template.
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.templates.insert(0, template.into());
+        self
+    }
+
+    /// Every path the input of `day` may be at, most preferred first.
+    pub fn candidates(&self, day: &Day, mode: &ExecutionMode) -> Vec<PathBuf> {
+        self.candidates_in(day.year, day.day, day.input_dir, mode)
+    }
+
+    /// Like [`InputLocator::candidates`], for a day that does not have to be
+    /// registered yet and whose crate keeps its inputs in `input_dir`.
+    pub fn candidates_in(
+        &self,
+        year: u16,
+        day: u8,
+        input_dir: &str,
+        mode: &ExecutionMode,
+    ) -> Vec<PathBuf> {
+        let dirs = self
+            .dirs
+            .iter()
+            .filter(|(only, _)| only.is_none_or(|only| only == year))
+            .map(|(_, dir)| dir.as_str())
+            .chain([input_dir]);
+        let templates: Vec<_> = self
+            .templates
+            .iter()
+            .map(String::as_str)
+            .chain([Self::DEFAULT_TEMPLATE])
+            .collect();
+
+        let mut candidates: Vec<PathBuf> = Vec::new();
+        for dir in dirs {
+            for template in &templates {
+                let path = PathBuf::from(expand(dir, year, day, mode))
+                    .join(expand(template, year, day, mode));
+                if !candidates.contains(&path) {
+                    candidates.push(path);
+                }
+            }
+        }
+        candidates
+    }
+
+    /// The first candidate that exists.
+    pub fn locate(&self, day: &Day, mode: &ExecutionMode) -> Result<PathBuf> {
+        let tried = self.candidates(day, mode);
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(Error::NoInputFound { tried }),
+        }
+    }
+
+    pub fn load(&self, day: &Day, mode: &ExecutionMode) -> Result<Input> {
+        read_input(self.locate(day, mode)?)
+    }
+
+    /// Every example input of `day` that exists: [`ExecutionMode::Test`]
+    /// followed by the named examples, numbers first.
+    pub fn examples(&self, day: &Day) -> Vec<ExecutionMode> {
+        const MARKER: &str = "*";
+
+        let mut names: Vec<String> = Vec::new();
+        for pattern in self.candidates(day, &ExecutionMode::Example(MARKER.to_string())) {
+            let (Some(dir), Some(pattern)) = (pattern.parent(), pattern.file_name()) else {
+                continue;
+            };
+            let Some((prefix, postfix)) = pattern.to_str().and_then(|p| p.split_once(MARKER))
+            else {
+                continue;
+            };
+            let Ok(entries) = fs::read_dir(dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let file_name = entry.file_name();
+                let name = file_name
+                    .to_str()
+                    .and_then(|name| name.strip_prefix(prefix)?.strip_suffix(postfix));
+                if let Some(name) = name
+                    && !name.is_empty()
+                    && !names.iter().any(|known| known == name)
+                {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort_by_key(|name| (name.parse::<u32>().unwrap_or(u32::MAX), name.clone()));
+
+        let test = self.locate(day, &ExecutionMode::Test).is_ok();
+        test.then_some(ExecutionMode::Test)
+            .into_iter()
+            .chain(names.into_iter().map(ExecutionMode::Example))
+            .collect()
+    }
+}
+
+fn expand(template: &str, year: u16, day: u8, mode: &ExecutionMode) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day:02}", &format!("{day:02}"))
+        .replace("{day}", &day.to_string())
+        .replace("{suffix}", &mode.suffix())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::tests::dummy_day;
+
+    const DAY: Day = dummy_day(5, "crate/inputs");
+
+    #[test]
+    fn default_candidates() {
+        let locator = InputLocator::default();
+        assert_eq!(
+            locator.candidates(&DAY, &ExecutionMode::Test),
+            vec![PathBuf::from("crate/inputs/day-5-test.txt")]
+        );
+    }
+
+    #[test]
+    fn candidates_by_precedence() {
+        let config = Config::parse("input_dir = config/{year}\ninput_dir.2023 = config-2023\ninput_dir.2025 = other\ninput_template = {day:02}.txt").unwrap();
+        let env = [("AOC_INPUT_DIR".to_string(), "env".to_string())];
+        let locator = InputLocator::from_sources(&config, env).with_dir("cli");
+
+        let candidates: Vec<_> = locator
+            .candidates(&DAY, &ExecutionMode::Normal)
+            .into_iter()
+            .map(|path| path.to_string_lossy().replace('\\', "/"))
+            .collect();
+        assert_eq!(
+            candidates,
+            [
+                "cli/05.txt",
+                "cli/day-5.txt",
+                "env/05.txt",
+                "env/day-5.txt",
+                "config-2023/05.txt",
+                "config-2023/day-5.txt",
+                "config/2023/05.txt",
+                "config/2023/day-5.txt",
+                "crate/inputs/05.txt",
+                "crate/inputs/day-5.txt",
+            ]
+        );
+    }
+
+    #[test]
+    fn named_examples() {
+        let dir = env::temp_dir().join(format!("aocutils-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file in [
+            "day-5-test.txt",
+            "day-5-test-larger.txt",
+            "day-5-test-10.txt",
+            "day-5-test-2.txt",
+            "day-5-test-2.answers",
+            "day-15-test-3.txt",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let locator = InputLocator::default().with_dir(dir.to_string_lossy());
+        let examples = locator.examples(&DAY);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let example = |name: &str| ExecutionMode::Example(name.to_string());
+        assert_eq!(
+            examples,
+            [
+                ExecutionMode::Test,
+                example("2"),
+                example("10"),
+                example("larger")
+            ]
+        );
+    }
+
+    #[test]
+    fn nothing_found() {
+        let error = InputLocator::default()
+            .locate(&DAY, &ExecutionMode::Normal)
+            .unwrap_err();
+        assert!(
+            matches!(error, Error::NoInputFound { tried } if tried == [PathBuf::from("crate/inputs/day-5.txt")])
+        );
+    }
+}
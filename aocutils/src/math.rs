@@ -0,0 +1,408 @@
+//! Integer maths without floating point: square roots, number theory and
+//! modular arithmetic, generic over the integer widths solutions use.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// The operations the functions of this module need from an integer type.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// The absolute value, which overflows for the minimum of signed types.
+    fn abs(self) -> Self;
+    /// The remainder in `0..modulus` for a positive `modulus`.
+    fn rem_euclid(self, modulus: Self) -> Self;
+    /// `self * other % modulus` for `self` and `other` in `0..modulus`,
+    /// without overflowing.
+    fn mul_mod(self, other: Self, modulus: Self) -> Self;
+}
+
+/// Integers that can be negative, which extended Euclid and CRT need.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+/// Integers that cannot be negative, which have square roots.
+pub trait Unsigned: Integer {
+    fn to_u128(self) -> u128;
+    /// Only called with values that fit.
+    fn from_u128(value: u128) -> Self;
+}
+
+/// `a * b % modulus` by doubling and adding, for types that have no wider type.
+fn mul_mod_by_doubling<T: Integer>(mut a: T, mut b: T, modulus: T) -> T {
+    let two = T::ONE + T::ONE;
+    // Adds in `0..modulus` without ever exceeding `modulus`.
+    let add = |x: T, y: T| match x >= modulus - y {
+        true => x - (modulus - y),
+        false => x + y,
+    };
+    let mut product = T::ZERO;
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            product = add(product, a);
+        }
+        a = add(a, a);
+        b = b / two;
+    }
+    product
+}
+
+macro_rules! impl_integer {
+    ($($type:ty: $mul_mod:expr),* $(,)?) => {
+        $(
+            impl Integer for $type {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$type>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$type>::checked_mul(self, other)
+                }
+
+                #[allow(unused_comparisons)]
+                fn abs(self) -> Self {
+                    match self < 0 {
+                        true => Self::ZERO - self,
+                        false => self,
+                    }
+                }
+
+                fn rem_euclid(self, modulus: Self) -> Self {
+                    <$type>::rem_euclid(self, modulus)
+                }
+
+                fn mul_mod(self, other: Self, modulus: Self) -> Self {
+                    let mul_mod: fn(Self, Self, Self) -> Self = $mul_mod;
+                    mul_mod(self, other, modulus)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8: |a, b, m| (a as u128 * b as u128 % m as u128) as u8,
+    u16: |a, b, m| (a as u128 * b as u128 % m as u128) as u16,
+    u32: |a, b, m| (a as u128 * b as u128 % m as u128) as u32,
+    u64: |a, b, m| (a as u128 * b as u128 % m as u128) as u64,
+    usize: |a, b, m| (a as u128 * b as u128 % m as u128) as usize,
+    u128: mul_mod_by_doubling,
+    i8: |a, b, m| (a as i128 * b as i128 % m as i128) as i8,
+    i16: |a, b, m| (a as i128 * b as i128 % m as i128) as i16,
+    i32: |a, b, m| (a as i128 * b as i128 % m as i128) as i32,
+    i64: |a, b, m| (a as i128 * b as i128 % m as i128) as i64,
+    isize: |a, b, m| (a as i128 * b as i128 % m as i128) as isize,
+    i128: mul_mod_by_doubling,
+);
+
+macro_rules! impl_marker {
+    (Signed: $($type:ty),*) => {
+        $(impl Signed for $type {})*
+    };
+    (Unsigned: $($type:ty),*) => {
+        $(
+            impl Unsigned for $type {
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+
+                fn from_u128(value: u128) -> Self {
+                    value as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_marker!(Signed: i8, i16, i32, i64, isize, i128);
+impl_marker!(Unsigned: u8, u16, u32, u64, usize, u128);
+
+/// The largest `r` with `r * r <= n`, computed without floating point.
+pub fn isqrt<T: Unsigned>(n: T) -> T {
+    let n = n.to_u128();
+    if n < 2 {
+        return T::from_u128(n);
+    }
+    // Newton's method from above converges to the floor of the root.
+    let mut root = 1 << (n.ilog2() / 2 + 1);
+    loop {
+        let next = (root + n / root) / 2;
+        if next >= root {
+            return T::from_u128(root);
+        }
+        root = next;
+    }
+}
+
+/// The greatest common divisor, which is never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, or `None` if it does not fit into `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    match gcd(a, b) {
+        gcd if gcd == T::ZERO => Some(T::ZERO),
+        gcd => (a.abs() / gcd).checked_mul(b.abs()),
+    }
+}
+
+/// The greatest common divisor of all values, 0 for none.
+pub fn gcd_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of all values, e.g. when several cycles align
+/// again. It is 1 for no values and `None` if it does not fit into `T`.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |multiple, value| lcm(multiple, value))
+}
+
+/// The greatest common divisor `g` of `a` and `b` together with `x` and `y`
+/// such that `a * x + b * y == g`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    match old_r < T::ZERO {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` has one.
+pub fn mod_inverse<T: Signed>(a: T, modulus: T) -> Option<T> {
+    let (gcd, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (gcd == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// `base` to the power of `exponent`, modulo `modulus`, for a non-negative
+/// `exponent` and a positive `modulus`.
+pub fn mod_pow<T: Integer>(base: T, mut exponent: T, modulus: T) -> T {
+    let two = T::ONE + T::ONE;
+    let mut base = base.rem_euclid(modulus);
+    let mut result = T::ONE.rem_euclid(modulus);
+    while exponent > T::ZERO {
+        if exponent % two == T::ONE {
+            result = result.mul_mod(base, modulus);
+        }
+        base = base.mul_mod(base, modulus);
+        exponent = exponent / two;
+    }
+    result
+}
+
+/// Solves `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs with
+/// the Chinese remainder theorem. The moduli need not be coprime.
+///
+/// Returns the smallest non-negative solution and the modulus all solutions
+/// share, or `None` if a modulus is not positive, the congruences contradict
+/// each other or the combined modulus does not fit into `T`.
+pub fn crt<T: Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    let (mut x, mut modulus) = (T::ZERO, T::ONE);
+    for (residue, next_modulus) in congruences {
+        if next_modulus <= T::ZERO {
+            return None;
+        }
+        let residue = residue.rem_euclid(next_modulus);
+        let (gcd, inverse, _) = extended_gcd(modulus, next_modulus);
+        let difference = residue - x;
+        if difference % gcd != T::ZERO {
+            return None;
+        }
+
+        // x + modulus * k solves both if k ≡ difference / gcd * inverse (mod next_modulus / gcd).
+        let step = next_modulus / gcd;
+        let k = (difference / gcd)
+            .rem_euclid(step)
+            .mul_mod(inverse.rem_euclid(step), step);
+        let combined = (modulus / gcd).checked_mul(next_modulus)?;
+        x = x.checked_add(modulus.checked_mul(k)?)?.rem_euclid(combined);
+        modulus = combined;
+    }
+    Some((x, modulus))
+}
+
+/// How many `a` in `0..=total` have `a * (total - a) > threshold`, or `None`
+/// if the computation overflows.
+///
+/// This is the number of ways to split `total` into two parts whose product
+/// beats `threshold`, e.g. holding a boat's button for `a` of `total`
+/// milliseconds to beat a record distance. The parts solve the quadratic
+/// `a² - total·a + threshold < 0`, whose roots are found with an exact
+/// integer square root.
+pub fn checked_count_splits_above(total: u128, threshold: u128) -> Option<u128> {
+    let product = |a: u128| a.checked_mul(total - a);
+    let discriminant = total
+        .checked_mul(total)?
+        .checked_sub(threshold.checked_mul(4)?);
+    let Some(discriminant) = discriminant else {
+        // Even the best split stays below the threshold.
+        return Some(0);
+    };
+
+    // The smallest winning `a` is next to the smaller root.
+    let mut first = (total - isqrt(discriminant).min(total)) / 2;
+    while first <= total / 2 && product(first)? <= threshold {
+        first += 1;
+    }
+    while first > 0 && product(first - 1)? > threshold {
+        first -= 1;
+    }
+    if first > total / 2 {
+        return Some(0);
+    }
+
+    // The winning parts are symmetric around `total / 2`.
+    Some(total - 2 * first + 1)
+}
+
+/// How many `a` in `0..=total` have `a * (total - a) > threshold`.
+///
+/// This cannot overflow, the products of two `u64` fit into a `u128`.
+pub fn count_splits_above(total: u64, threshold: u64) -> u64 {
+    checked_count_splits_above(total.into(), threshold.into())
+        .expect("products of u64 fit into u128") as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn integer_square_root() {
+        for n in 0..10_000_u64 {
+            let root = isqrt(n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n, "{n}");
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt(255_u8), 15);
+    }
+
+    #[test]
+    fn number_theory() {
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0_u8, 0), 0);
+        assert_eq!(lcm(4_u8, 6), Some(12));
+        assert_eq!(lcm(200_u8, 3), None);
+        assert_eq!(lcm_all([2_u64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u32>::new()), Some(1));
+        assert_eq!(gcd_all([12_i32, 18, 27]), 3);
+        assert_eq!(extended_gcd(240_i32, 46), (2, -9, 47));
+        assert_eq!(mod_inverse(3_i64, 11), Some(4));
+        assert_eq!(mod_inverse(2_i64, 4), None);
+        assert_eq!(mod_pow(2_u64, 10, 1000), 24);
+        assert_eq!(mod_pow(5_u8, 0, 1), 0);
+        assert_eq!(crt([(2_i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1_i32, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1_i32, 4), (2, 6)]), None);
+        assert_eq!(crt(Vec::<(i8, i8)>::new()), Some((0, 1)));
+        assert_eq!(crt([(1_i32, 4), (0, 0)]), None);
+        assert_eq!(crt([(1_i32, -4)]), None);
+    }
+
+    #[test]
+    fn wide_modular_arithmetic() {
+        // A prime close to 2^127, whose products only fit by doubling.
+        let prime = i128::MAX;
+        assert_eq!(mod_pow(3, prime - 1, prime), 1);
+        let inverse = mod_inverse(prime - 2, prime).unwrap();
+        assert_eq!((prime - 2).mul_mod(inverse, prime), 1);
+        assert_eq!(mod_pow(5_u128, prime as u128 - 1, prime as u128), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn square_roots(n: u128) {
+            let root = isqrt(n);
+            prop_assert!(root * root <= n);
+            prop_assert!((root + 1).checked_mul(root + 1).is_none_or(|square| square > n));
+        }
+
+        #[test]
+        fn gcd_and_lcm(a in 1..=u32::MAX as u64, b in 1..=u32::MAX as u64) {
+            let gcd = gcd(a, b);
+            prop_assert_eq!(a % gcd, 0);
+            prop_assert_eq!(b % gcd, 0);
+            prop_assert_eq!(gcd * lcm(a, b).unwrap(), a * b);
+        }
+
+        #[test]
+        fn bezout(a in -1_000_000_000_i64..1_000_000_000, b in -1_000_000_000_i64..1_000_000_000) {
+            let (gcd, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(gcd, super::gcd(a, b));
+            prop_assert_eq!(a * x + b * y, gcd);
+        }
+
+        #[test]
+        fn inverses(a: i64, modulus in 2_i64..=i64::MAX) {
+            match mod_inverse(a, modulus) {
+                Some(inverse) => prop_assert_eq!(a.rem_euclid(modulus).mul_mod(inverse, modulus), 1),
+                None => prop_assert_ne!(gcd(a, modulus), 1),
+            }
+        }
+
+        #[test]
+        fn powers(base: u32, exponent in 0_u32..64, modulus in 1_u32..=u32::MAX) {
+            let naive = (0..exponent).fold(1 % modulus, |power, _| power.mul_mod(base % modulus, modulus));
+            prop_assert_eq!(mod_pow(base, exponent, modulus), naive);
+        }
+
+        #[test]
+        fn chinese_remainders(x in 0_i64..1_000_000_000, moduli in prop::collection::vec(1_i64..1000, 1..6)) {
+            let (solution, modulus) = crt(moduli.iter().map(|m| (x % m, *m))).unwrap();
+            prop_assert_eq!(modulus, lcm_all(moduli.iter().copied()).unwrap());
+            prop_assert_eq!(solution, x % modulus);
+        }
+    }
+
+    #[test]
+    fn splits_above() {
+        let brute_force = |total: u64, threshold: u64| {
+            (0..=total).filter(|a| a * (total - a) > threshold).count()
+        };
+        for total in 0..60 {
+            for threshold in 0..1000 {
+                assert_eq!(
+                    count_splits_above(total, threshold) as usize,
+                    brute_force(total, threshold),
+                    "{total} {threshold}"
+                );
+            }
+        }
+
+        assert_eq!(count_splits_above(71530, 940200), 71503);
+        assert_eq!(count_splits_above(u64::MAX, u64::MAX), u64::MAX - 3);
+        assert_eq!(checked_count_splits_above(u128::MAX, 0), None);
+    }
+}
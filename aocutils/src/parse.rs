@@ -0,0 +1,357 @@
+use crate::{Error, Result};
+use std::{fmt, str::FromStr};
+
+/// A line of the input, or a part of one, that knows where it is so parse
+/// errors can point at its line and column.
+///
+/// Columns are counted in bytes from 1. The methods that cut a line into
+/// parts trim the whitespace around each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    text: &'a str,
+    number: usize,
+    column: usize,
+}
+
+impl<'a> Line<'a> {
+    /// The line `text`, where `number` is counted from 1.
+    pub fn new(text: &'a str, number: usize) -> Self {
+        Line {
+            text,
+            number,
+            column: 1,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// A parse error pointing at the start of this part of the line.
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::parse_at(self.number, self.column, message)
+    }
+
+    /// A parse error pointing `offset` bytes into this part of the line.
+    pub fn error_at(&self, offset: usize, message: impl Into<String>) -> Error {
+        Error::parse_at(self.number, self.column + offset, message)
+    }
+
+    /// The line without whitespace at either end.
+    pub fn trim(&self) -> Line<'a> {
+        self.part(self.text.trim())
+    }
+
+    /// `part`, which must be a slice of this line, along with its position.
+    fn part(&self, part: &'a str) -> Line<'a> {
+        let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        Line {
+            text: part,
+            number: self.number,
+            column: self.column + offset,
+        }
+    }
+
+    /// The empty part right after the line, for errors about what is missing.
+    fn end(&self) -> Line<'a> {
+        self.part(&self.text[self.text.len()..])
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Line<'a>> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.part(rest)),
+            None => Err(self.error(format!("expected {prefix:?}"))),
+        }
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Line<'a>> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(self.part(rest)),
+            None => Err(self.end().error(format!("expected {suffix:?}"))),
+        }
+    }
+
+    /// The parts before and after the first `delimiter`.
+    pub fn split_once(&self, delimiter: &str) -> Result<(Line<'a>, Line<'a>)> {
+        match self.text.split_once(delimiter) {
+            Some((before, after)) => Ok((self.part(before).trim(), self.part(after).trim())),
+            None => Err(self.error(format!("expected {delimiter:?}"))),
+        }
+    }
+
+    /// The parts between the `separator`s.
+    pub fn split(&self, separator: &str) -> impl Iterator<Item = Line<'a>> {
+        let line = *self;
+        self.text
+            .split(separator)
+            .map(move |part| line.part(part).trim())
+    }
+
+    /// The parts between runs of whitespace.
+    pub fn fields(&self) -> Fields<'a> {
+        Fields {
+            line: *self,
+            rest: self.text,
+        }
+    }
+
+    /// Parses the whole part, e.g. into a number.
+    pub fn parse<T: FromStr>(&self) -> Result<T>
+    where
+        T::Err: fmt::Display,
+    {
+        self.text
+            .parse()
+            .map_err(|error| self.error(format!("invalid value {:?}: {error}", self.text)))
+    }
+
+    /// Parses every part between the `separator`s.
+    pub fn list<T: FromStr>(&self, separator: &str) -> Result<Vec<T>>
+    where
+        T::Err: fmt::Display,
+    {
+        self.split(separator).map(|part| part.parse()).collect()
+    }
+
+    /// Parses every whitespace separated field.
+    pub fn field_list<T: FromStr>(&self) -> Result<Vec<T>>
+    where
+        T::Err: fmt::Display,
+    {
+        self.fields().map(|field| field.parse()).collect()
+    }
+
+    /// Parses exactly `N` whitespace separated fields.
+    pub fn parse_fields<T: FromStr, const N: usize>(&self) -> Result<[T; N]>
+    where
+        T::Err: fmt::Display,
+    {
+        self.field_list()?.try_into().map_err(|fields: Vec<T>| {
+            self.error(format!("expected {N} fields, found {}", fields.len()))
+        })
+    }
+
+    /// Every integer in the line. A `-` right before the digits makes them
+    /// negative, so `x=-3..5` holds `-3` and `5`.
+    pub fn integers<T: FromStr>(&self) -> Result<Vec<T>>
+    where
+        T::Err: fmt::Display,
+    {
+        let bytes = self.text.as_bytes();
+        let is_digit = |index: usize| bytes.get(index).is_some_and(u8::is_ascii_digit);
+        let mut integers = Vec::new();
+        let mut index = 0;
+        while index < bytes.len() {
+            let start = index;
+            if bytes[index] == b'-' && is_digit(index + 1) {
+                index += 1;
+            }
+            if !is_digit(index) {
+                index = start + 1;
+                continue;
+            }
+            while is_digit(index) {
+                index += 1;
+            }
+            integers.push(self.part(&self.text[start..index]).parse()?);
+        }
+        Ok(integers)
+    }
+
+    /// Splits a `key: list | list` line into the key and the whitespace
+    /// separated values of each list.
+    pub fn key_lists<T: FromStr>(&self) -> Result<(Line<'a>, Vec<Vec<T>>)>
+    where
+        T::Err: fmt::Display,
+    {
+        let (key, lists) = self.split_once(":")?;
+        let lists = lists
+            .split("|")
+            .map(|list| list.field_list())
+            .collect::<Result<_>>()?;
+        Ok((key, lists))
+    }
+}
+
+impl fmt::Display for Line<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+/// The whitespace separated fields of a [`Line`], which can also be parsed
+/// one by one into different types.
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+    line: Line<'a>,
+    rest: &'a str,
+}
+
+impl<'a> Fields<'a> {
+    /// Parses the next field, which has to be there.
+    pub fn parse_next<T: FromStr>(&mut self) -> Result<T>
+    where
+        T::Err: fmt::Display,
+    {
+        match self.next() {
+            Some(field) => field.parse(),
+            None => Err(self.line.end().error("expected another field")),
+        }
+    }
+
+    /// Everything after the fields used so far, which has to be something.
+    pub fn rest(self) -> Result<Line<'a>> {
+        match self.line.part(self.rest).trim() {
+            rest if rest.is_empty() => Err(self.line.end().error("expected another field")),
+            rest => Ok(rest),
+        }
+    }
+
+    /// Checks that all fields have been used.
+    pub fn finish(mut self) -> Result<()> {
+        match self.next() {
+            Some(field) => Err(field.error(format!("unexpected {:?}", field.as_str()))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Line<'a>> {
+        let rest = self.rest.trim_start();
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (field, rest) = rest.split_at(end);
+        self.rest = rest;
+        (!field.is_empty()).then(|| self.line.part(field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns<'a>(parts: impl Iterator<Item = Line<'a>>) -> Vec<(&'a str, usize)> {
+        parts.map(|part| (part.as_str(), part.column())).collect()
+    }
+
+    #[test]
+    fn split_and_fields() {
+        let line = Line::new("Game 12: 3 blue, 4 red; 1 red", 3);
+        let (game, draws) = line.split_once(":").unwrap();
+        assert_eq!(
+            game.strip_prefix("Game ").unwrap().parse::<u32>().unwrap(),
+            12
+        );
+        assert_eq!(
+            columns(draws.split(";")),
+            [("3 blue, 4 red", 10), ("1 red", 25)]
+        );
+        assert_eq!(
+            columns(draws.split(";").flat_map(|draw| draw.fields())),
+            [
+                ("3", 10),
+                ("blue,", 12),
+                ("4", 18),
+                ("red", 20),
+                ("1", 25),
+                ("red", 27)
+            ]
+        );
+        assert_eq!(Line::new("1, 2,3", 1).list::<u8>(",").unwrap(), [1, 2, 3]);
+
+        let mut fields = Line::new("  50 98 x", 1).fields();
+        assert_eq!(fields.parse_next::<i64>().unwrap(), 50);
+        assert_eq!(fields.next().map(|field| field.column()), Some(6));
+        assert!(matches!(
+            fields.clone().finish(),
+            Err(Error::Parse {
+                column: Some(9),
+                ..
+            })
+        ));
+        fields.next();
+        assert!(matches!(
+            fields.parse_next::<i64>(),
+            Err(Error::Parse {
+                column: Some(10),
+                ..
+            })
+        ));
+
+        let mut fields = Line::new("1 two words ", 1).fields();
+        assert_eq!(fields.parse_next::<u8>().unwrap(), 1);
+        assert_eq!(fields.clone().rest().unwrap().as_str(), "two words");
+        fields.next();
+        fields.next();
+        assert!(fields.rest().is_err());
+    }
+
+    #[test]
+    fn typed_values() {
+        let line = Line::new("52 50 48", 7);
+        assert_eq!(line.parse_fields::<i64, 3>().unwrap(), [52, 50, 48]);
+        assert!(line.parse_fields::<i64, 2>().is_err());
+
+        let error = Line::new("1 2 x4", 7).field_list::<u32>().unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Parse {
+                line: 7,
+                column: Some(5),
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "failed to parse line 7, column 5: invalid value \"x4\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn integers() {
+        let line = Line::new("p=-3,10 v=4-5 - x -", 1);
+        assert_eq!(line.integers::<i32>().unwrap(), [-3, 10, 4, -5]);
+        assert!(Line::new("", 1).integers::<i32>().unwrap().is_empty());
+        assert!(matches!(
+            Line::new("1 300", 1).integers::<u8>(),
+            Err(Error::Parse {
+                column: Some(3),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn key_lists() {
+        let line = Line::new("Card   1: 41 48 | 83 86  6", 1);
+        let (key, lists) = line.key_lists::<u32>().unwrap();
+        assert_eq!(key.as_str(), "Card   1");
+        assert_eq!(lists, [vec![41, 48], vec![83, 86, 6]]);
+        assert!(matches!(
+            Line::new("Card 1 41 | 83", 4).key_lists::<u32>(),
+            Err(Error::Parse {
+                line: 4,
+                column: Some(1),
+                ..
+            })
+        ));
+        assert_eq!(
+            line.strip_suffix(" 9").unwrap_err().to_string(),
+            "failed to parse line 1, column 27: expected \" 9\""
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a grid. `x` grows to the right and `y` downwards, like the
+/// lines and columns of an input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// A signed vector: an offset between points or a position that may leave the grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    /// `self + offset`, or `None` if that is left of or above the origin.
+    pub fn checked_add(self, offset: Vec2) -> Option<Point> {
+        Some(Point {
+            x: self.x.checked_add_signed(offset.x.try_into().ok()?)?,
+            y: self.y.checked_add_signed(offset.y.try_into().ok()?)?,
+        })
+    }
+
+    /// The next point in `direction`, or `None` if there is none.
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        self.checked_add(direction.offset())
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The index of the point in a row-major grid of `width` columns, or
+    /// `None` if it is not one of its columns.
+    pub fn to_index(self, width: usize) -> Option<usize> {
+        match self.x < width {
+            true => self.y.checked_mul(width)?.checked_add(self.x),
+            false => None,
+        }
+    }
+
+    /// The point at `index` of a row-major grid of `width` columns.
+    pub fn from_index(index: usize, width: usize) -> Option<Point> {
+        Some(Point {
+            x: index.checked_rem(width)?,
+            y: index / width,
+        })
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Turns by 90° clockwise, as seen on the screen.
+    pub fn rotate_right(self) -> Self {
+        Vec2 {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Turns by 90° counterclockwise, as seen on the screen.
+    pub fn rotate_left(self) -> Self {
+        Vec2 {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    /// The distance from the origin when only moving along the axes.
+    pub fn manhattan(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// The distance from the origin when diagonal steps are allowed.
+    pub fn chebyshev(self) -> u64 {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<(i64, i64)> for Vec2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl TryFrom<Vec2> for Point {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(vector: Vec2) -> Result<Self, Self::Error> {
+        Ok(Point {
+            x: vector.x.try_into()?,
+            y: vector.y.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Point> for Vec2 {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok(Vec2 {
+            x: point.x.try_into()?,
+            y: point.y.try_into()?,
+        })
+    }
+}
+
+macro_rules! impl_ops {
+    ($type:ty, $scalar:ty) => {
+        impl Add for $type {
+            type Output = $type;
+
+            fn add(self, other: $type) -> $type {
+                Self {
+                    x: self.x + other.x,
+                    y: self.y + other.y,
+                }
+            }
+        }
+
+        impl Mul<$scalar> for $type {
+            type Output = $type;
+
+            fn mul(self, factor: $scalar) -> $type {
+                Self {
+                    x: self.x * factor,
+                    y: self.y * factor,
+                }
+            }
+        }
+
+        impl AddAssign for $type {
+            fn add_assign(&mut self, other: $type) {
+                *self = *self + other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point, usize);
+impl_ops!(Vec2, i64);
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+/// The offset from `other` to `self`, which may point left or up.
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Point) -> Vec2 {
+        let vector = |point| Vec2::try_from(point).expect("coordinates fit into an i64");
+        vector(self) - vector(other)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+/// The four and eight compass directions, with north pointing up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// North, east, south and west.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions clockwise, starting with north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The step one has to take to move in this direction.
+    pub fn offset(self) -> Vec2 {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        };
+        Vec2 { x, y }
+    }
+
+    /// Turns clockwise by `eighths` of a full turn, or counterclockwise if negative.
+    pub fn rotate(self, eighths: i32) -> Self {
+        Direction::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let point = Point::new(3, 4);
+        assert_eq!(point + Point::new(1, 1), Point::new(4, 5));
+        assert_eq!(point * 2, Point::new(6, 8));
+        assert_eq!(Point::new(1, 6) - point, Vec2::new(-2, 2));
+        assert_eq!(
+            point.checked_add(Point::ORIGIN - point),
+            Some(Point::ORIGIN)
+        );
+        assert_eq!(point.checked_add(Vec2::new(-3, 1)), Some(Point::new(0, 5)));
+        assert_eq!(point.checked_add(Vec2::new(-4, 0)), None);
+        assert_eq!(Point::ORIGIN.step(Direction::North), None);
+        assert_eq!(
+            Point::ORIGIN.step(Direction::SouthEast),
+            Some(Point::new(1, 1))
+        );
+
+        assert_eq!(-Vec2::new(1, -2), Vec2::new(-1, 2));
+        assert_eq!(Vec2::new(1, 2) - Vec2::new(3, 3), Vec2::new(-2, -1));
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point::new(1, 7), Point::new(4, 3));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Vec2::new(-3, 4).manhattan(), 7);
+        assert_eq!(Vec2::new(-3, 4).chebyshev(), 4);
+    }
+
+    #[test]
+    fn rotation() {
+        let north = Direction::North.offset();
+        assert_eq!(north.rotate_right(), Direction::East.offset());
+        assert_eq!(north.rotate_left(), Direction::West.offset());
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::NorthEast.opposite(), Direction::SouthWest);
+        assert_eq!(Direction::North.rotate(-1), Direction::NorthWest);
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_right().rotate_left(),
+                direction.offset()
+            );
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Point::try_from(Vec2::new(2, 3)), Ok(Point::new(2, 3)));
+        assert!(Point::try_from(Vec2::new(-1, 3)).is_err());
+        assert!(Vec2::try_from(Point::new(usize::MAX, 0)).is_err());
+
+        assert_eq!(Point::new(2, 1).to_index(4), Some(6));
+        assert_eq!(Point::new(4, 1).to_index(4), None);
+        assert_eq!(Point::new(0, usize::MAX).to_index(2), None);
+        assert_eq!(Point::from_index(6, 4), Some(Point::new(2, 1)));
+        assert_eq!(Point::from_index(6, 0), None);
+    }
+}
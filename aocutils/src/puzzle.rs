@@ -0,0 +1,279 @@
+use crate::{
+    Error, ExecutionMode, Result,
+    answers::{ExpectedAnswers, answers_path},
+    locate::InputLocator,
+    registry::Day,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// An example input from the puzzle page along with the answers it leads to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// [`ExecutionMode::Test`] for the first example, then `test-2` and so on.
+    pub mode: ExecutionMode,
+    pub input: String,
+    pub answers: ExpectedAnswers,
+}
+
+impl Example {
+    /// Writes the example and its answers where `locator` finds the inputs
+    /// of `day`, and returns the path of the input.
+    ///
+    /// Files that are missing or empty are written, others are left alone.
+    pub fn save(&self, locator: &InputLocator, day: &Day) -> Result<PathBuf> {
+        let path = match locator.locate(day, &self.mode) {
+            Ok(path) => path,
+            Err(Error::NoInputFound { tried }) => tried
+                .into_iter()
+                .next()
+                .expect("there is always at least one candidate"),
+            Err(error) => return Err(error),
+        };
+        let answers = answers_path(&path);
+        for path in [&path, &answers] {
+            if fs::metadata(path).is_ok_and(|file| file.len() > 0) {
+                return Err(Error::AlreadyExists { path: path.clone() });
+            }
+        }
+
+        for (path, content) in [
+            (&path, self.input.clone()),
+            (&answers, self.answers.to_string()),
+        ] {
+            let io_error = |source| Error::Io {
+                path: path.clone(),
+                source,
+            };
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(io_error)?;
+            }
+            fs::write(path, content).map_err(io_error)?;
+        }
+        Ok(path)
+    }
+}
+
+/// Reads the examples from the puzzle page saved at `path`.
+pub fn load_examples(path: &Path) -> Result<Vec<Example>> {
+    let html = fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(examples(&html))
+}
+
+/// Finds the examples of a puzzle page and guesses their answers.
+///
+/// Every part of the puzzle is an `<article>`. Its example is the first
+/// `<pre><code>` block and its answer is the last emphasized code, such as
+/// `<code><em>142</em></code>`. A part without a block of its own uses the
+/// example of the part before it.
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    for (part, article) in articles(html).into_iter().take(2).enumerate() {
+        let index = match first_block(article) {
+            Some(input) => match examples.iter().position(|example| example.input == input) {
+                Some(index) => Some(index),
+                None => {
+                    let mode = match examples.len() {
+                        0 => ExecutionMode::Test,
+                        count => ExecutionMode::Example((count + 1).to_string()),
+                    };
+                    examples.push(Example {
+                        mode,
+                        input,
+                        answers: ExpectedAnswers::default(),
+                    });
+                    Some(examples.len() - 1)
+                }
+            },
+            None => examples.len().checked_sub(1),
+        };
+
+        if let (Some(index), Some(answer)) = (index, last_answer(article)) {
+            let answers = &mut examples[index].answers;
+            match part {
+                0 => answers.part_one = Some(answer),
+                _ => answers.part_two = Some(answer),
+            }
+        }
+    }
+    examples
+}
+
+/// The inside of every `<article>`, or the whole page if there is none.
+pub(crate) fn articles(html: &str) -> Vec<&str> {
+    let articles: Vec<_> = html
+        .split("<article")
+        .skip(1)
+        .map(|article| {
+            let inside = article
+                .split_once('>')
+                .map_or(article, |(_, inside)| inside);
+            inside.split("</article>").next().unwrap_or(inside)
+        })
+        .collect();
+    match articles.is_empty() {
+        true => vec![html],
+        false => articles,
+    }
+}
+
+/// The text between the first `open` and the `close` after it.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Option<&'a str> {
+    let (_, rest) = html.split_once(open)?;
+    Some(rest.split_once(close)?.0)
+}
+
+fn first_block(article: &str) -> Option<String> {
+    between(article, "<pre><code>", "</code></pre>")
+        .map(text)
+        .filter(|input| !input.trim().is_empty())
+}
+
+fn last_answer(article: &str) -> Option<String> {
+    [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .filter_map(|(open, close)| {
+        let start = article.rfind(open)?;
+        Some((start, between(&article[start..], open, close)?))
+    })
+    .max_by_key(|(start, _)| *start)
+    .map(|(_, value)| text(value).trim().to_string())
+    .filter(|value| !value.is_empty())
+}
+
+/// `html` without its tags and with its entities decoded.
+pub(crate) fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            char if !in_tag => text.push(char),
+            _ => (),
+        }
+    }
+
+    let mut decoded = String::new();
+    let mut rest = text.as_str();
+    while let Some(start) = rest.find('&') {
+        decoded += &rest[..start];
+        rest = &rest[start + 1..];
+        let entity = rest
+            .split_once(';')
+            .and_then(|(name, after)| Some((entity(name)?, after)));
+        match entity {
+            Some((char, after)) => {
+                decoded.push(char);
+                rest = after;
+            }
+            None => decoded.push('&'),
+        }
+    }
+    decoded + rest
+}
+
+/// The character of the entity `&name;`.
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        name => {
+            let code = match name.strip_prefix("#x") {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::tests::dummy_day;
+    use std::env;
+
+    const PAGE: &str = "<html><main>\
+        <article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2>\
+        <p>For example:</p><pre><code>1abc2\npqr3stu8vwx\n</code></pre>\
+        <p>The values are <code>12</code> and <code>38</code>.</p>\
+        <p>Adding these together produces <code><em>50</em></code>.</p></article>\
+        <p>Your puzzle answer was <code>54388</code>.</p>\
+        <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\
+        <pre><code>two1<em>nine</em>\na &lt; b &amp;&#x20;c\n</code></pre>\
+        <p>Now it is <em><code>281</code></em>, not <code><em>50</em></code>!</p>\
+        <p>Finally, <em><code>29</code></em>.</p></article></main></html>";
+
+    #[test]
+    fn find_examples() {
+        let examples = examples(PAGE);
+        assert_eq!(
+            examples,
+            [
+                Example {
+                    mode: ExecutionMode::Test,
+                    input: "1abc2\npqr3stu8vwx\n".to_string(),
+                    answers: ExpectedAnswers {
+                        part_one: Some("50".to_string()),
+                        part_two: None,
+                    },
+                },
+                Example {
+                    mode: ExecutionMode::Example("2".to_string()),
+                    input: "two1nine\na < b & c\n".to_string(),
+                    answers: ExpectedAnswers {
+                        part_one: None,
+                        part_two: Some("29".to_string()),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn shared_example() {
+        let page = "<article><pre><code>3 4\n</code></pre><code><em>7</em></code></article>\
+            <article><p>Now multiply: <code><em>12</em></code>.</p></article>";
+        let shared = examples(page);
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared[0].answers.to_string(), "1: 7\n2: 12\n");
+
+        assert_eq!(examples("<p>Nothing to see.</p>"), []);
+    }
+
+    #[test]
+    fn save_example() {
+        let dir = env::temp_dir().join(format!("aocutils-puzzle-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let locator = InputLocator::default().with_dir(dir.to_string_lossy());
+        let day = dummy_day(1, "crate/inputs");
+        let example = &examples(PAGE)[1];
+
+        // The empty placeholder of a new day is filled in.
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day-1-test-2.txt"), "").unwrap();
+        let path = example.save(&locator, &day).unwrap();
+        assert_eq!(path, dir.join("day-1-test-2.txt"));
+        assert_eq!(
+            fs::read_to_string(dir.join("day-1-test-2.answers")).unwrap(),
+            "2: 29\n"
+        );
+        assert!(matches!(
+            example.save(&locator, &day),
+            Err(Error::AlreadyExists { path: existing }) if existing == path
+        ));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{Answer, Error, Input, Part, Result, Solver, TimedAnswer, Timings, solution::timed};
+use std::{sync::OnceLock, time::Duration};
+
+/// A day that registered itself with [`register_day!`](crate::register_day),
+/// or whose parts registered themselves with [`aoc`](crate::aoc).
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The `inputs` directory of the crate that registered the day.
+    pub input_dir: &'static str,
+    pub solution: &'static dyn Solver,
+}
+
+inventory::collect!(Day);
+
+/// Registers a [`Solution`](crate::Solution) for the given year and day.
+///
+/// ```ignore
+/// register_day!(2023, 1, "Trebuchet?!", Day1);
+/// ```
+#[macro_export]
+macro_rules! register_day {
+    ($year:expr, $day:expr, $title:expr, $solution:expr) => {
+        $crate::inventory::submit! {
+            $crate::registry::Day {
+                year: $year,
+                day: $day,
+                title: $title,
+                input_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+                solution: &$solution,
+            }
+        }
+    };
+}
+
+/// One part of a day, registered with [`aoc`](crate::aoc).
+pub struct PartFn {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The title of the day, which only one of its parts needs to give.
+    pub title: Option<&'static str>,
+    pub input_dir: &'static str,
+    pub solve: fn(&Input) -> Result<Answer>,
+}
+
+inventory::collect!(PartFn);
+
+/// The parts of a day that were registered one by one. They parse the input
+/// themselves, so there is no time for parsing it on its own.
+struct Parts {
+    one: Option<fn(&Input) -> Result<Answer>>,
+    two: Option<fn(&Input) -> Result<Answer>>,
+}
+
+impl Solver for Parts {
+    fn solve(&self, input: &Input, part: Option<Part>) -> Result<Timings> {
+        let solve_part = |selected: Part, solve: Option<fn(&Input) -> Result<Answer>>| {
+            if part.is_some_and(|part| part != selected) {
+                return Ok(None);
+            }
+            let (answer, duration) = match solve {
+                Some(solve) => timed(|| solve(input))?,
+                None => (Answer::Unsolved, Duration::ZERO),
+            };
+            Ok(Some(TimedAnswer { answer, duration }))
+        };
+
+        Ok(Timings {
+            parse: Duration::ZERO,
+            part_one: solve_part(Part::One, self.one)?,
+            part_two: solve_part(Part::Two, self.two)?,
+        })
+    }
+}
+
+/// The days put together from their registered parts. A day registered
+/// as a whole takes precedence over its parts.
+fn part_days() -> &'static [Day] {
+    static DAYS: OnceLock<Vec<Day>> = OnceLock::new();
+    DAYS.get_or_init(|| {
+        let mut parts: Vec<(&PartFn, Parts)> = Vec::new();
+        for part in inventory::iter::<PartFn> {
+            let index = match parts
+                .iter()
+                .position(|(first, _)| (first.year, first.day) == (part.year, part.day))
+            {
+                Some(index) => index,
+                None => {
+                    parts.push((
+                        part,
+                        Parts {
+                            one: None,
+                            two: None,
+                        },
+                    ));
+                    parts.len() - 1
+                }
+            };
+            let (first, solver) = &mut parts[index];
+            if first.title.is_none() {
+                *first = part;
+            }
+            match part.part {
+                Part::One => solver.one = Some(part.solve),
+                Part::Two => solver.two = Some(part.solve),
+            }
+        }
+
+        parts
+            .into_iter()
+            .filter(|(part, _)| whole_day(part.year, part.day).is_none())
+            .map(|(part, solver)| Day {
+                year: part.year,
+                day: part.day,
+                title: part
+                    .title
+                    .unwrap_or_else(|| format!("Day {}", part.day).leak()),
+                input_dir: part.input_dir,
+                solution: Box::leak(Box::new(solver)),
+            })
+            .collect()
+    })
+}
+
+fn whole_day(year: u16, day: u8) -> Option<&'static Day> {
+    inventory::iter::<Day>
+        .into_iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+/// All registered days, ordered by year and day.
+pub fn days() -> Vec<&'static Day> {
+    let mut days: Vec<_> = inventory::iter::<Day>
+        .into_iter()
+        .chain(part_days())
+        .collect();
+    days.sort_by_key(|day| (day.year, day.day));
+    days
+}
+
+/// All registered days of one year, ordered by day.
+pub fn days_of_year(year: u16) -> Vec<&'static Day> {
+    days().into_iter().filter(|day| day.year == year).collect()
+}
+
+/// The day registered for the given year and day number, if any.
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    whole_day(year, day).or_else(|| {
+        part_days()
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day)
+    })
+}
+
+/// Like [`find`], but fails with [`Error::UnknownDay`] for unregistered days.
+pub fn get(year: u16, day: u8) -> Result<&'static Day> {
+    find(year, day).ok_or(Error::UnknownDay { year, day })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{Solution, aoc, aoc_example};
+
+    /// A solution that solves nothing, for tests that only need a day.
+    struct Nothing;
+
+    impl Solution for Nothing {
+        type Parsed<'a> = ();
+
+        fn parse<'a>(&self, _input: &'a Input) -> Result<Self::Parsed<'a>> {
+            Ok(())
+        }
+
+        fn part_one(&self, _parsed: &Self::Parsed<'_>) -> Result<Answer> {
+            Ok(Answer::Unsolved)
+        }
+
+        fn part_two(&self, _parsed: &Self::Parsed<'_>) -> Result<Answer> {
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    /// Day `day` of 2023, which solves nothing and keeps its inputs in `input_dir`.
+    pub(crate) const fn dummy_day(day: u8, input_dir: &'static str) -> Day {
+        Day {
+            year: 2023,
+            day,
+            title: "Test",
+            input_dir,
+            solution: &Nothing,
+        }
+    }
+
+    #[aoc(year = 2015, day = 1, part = 2, title = "Not Quite Lisp")]
+    #[aoc_example(input = "()())", answer = 5)]
+    fn basement(input: &Input) -> Result<usize> {
+        let mut floor = 0;
+        for (position, char) in input.raw().chars().enumerate() {
+            floor += match char {
+                '(' => 1,
+                ')' => -1,
+                char => return Err(Error::parse(1, format!("unexpected {char:?}"))),
+            };
+            if floor < 0 {
+                return Ok(position + 1);
+            }
+        }
+        Err(Error::parse(1, "never enters the basement"))
+    }
+
+    #[aoc(year = 2015, day = 2, part = 1)]
+    #[aoc_example(input = "2x3x4", answer = 58)]
+    #[aoc_example(input = "1x1x10", answer = 43)]
+    fn wrapping_paper(input: &Input) -> Result<u32> {
+        let line = input.line(0)?;
+        let [l, w, h] = line.list::<u32>("x")?[..] else {
+            return Err(line.error("expected three sides"));
+        };
+        let sides = [l * w, w * h, h * l];
+        Ok(2 * sides.iter().sum::<u32>() + sides.iter().min().unwrap())
+    }
+
+    #[test]
+    fn registered_parts() {
+        let day = get(2015, 1).unwrap();
+        assert_eq!(day.title, "Not Quite Lisp");
+        assert!(day.input_dir.ends_with("/inputs"));
+        let timings = day.solution.solve(&Input::new(")"), None).unwrap();
+        assert_eq!(timings.part_one.unwrap().answer, Answer::Unsolved);
+        assert_eq!(timings.part_two.unwrap().answer, Answer::Number(1));
+        assert!(day.solution.solve(&Input::new("(x"), None).is_err());
+        let timings = day
+            .solution
+            .solve(&Input::new("(x"), Some(Part::One))
+            .unwrap();
+        assert_eq!(timings.part_two, None);
+
+        assert_eq!(get(2015, 2).unwrap().title, "Day 2");
+        let years: Vec<_> = days().iter().map(|day| (day.year, day.day)).collect();
+        assert_eq!(years, [(2015, 1), (2015, 2)]);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    Error, ExecutionMode, Result, answers::answers_path, config::Config, locate::InputLocator,
+    puzzle::Example,
+};
+use std::{
+    fs,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+/// The files a new day starts with: its solution, made from a template, an
+/// empty file for the personal input and the examples with their answers.
+/// Without any examples, an empty one is created to paste the example into.
+///
+/// The template can use `{year}`, `{day}`, `{day:02}`, `{title}` and
+/// `{title:?}`, which is the title as a string literal. The default one solves
+/// nothing yet and has a test that runs the examples. Year crates declare their
+/// days from the files in `src/days`, so nothing else has to be touched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffold {
+    year: u16,
+    day: u8,
+    title: String,
+    template: String,
+    examples: Vec<Example>,
+}
+
+impl Scaffold {
+    pub const DEFAULT_TEMPLATE: &str = include_str!("../templates/day.rs");
+
+    pub fn new(year: u16, day: u8) -> Self {
+        Scaffold {
+            year,
+            day,
+            title: format!("Day {day}"),
+            template: Self::DEFAULT_TEMPLATE.to_string(),
+            examples: Vec::new(),
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.template = template.into();
+        self
+    }
+
+    /// Creates these examples, e.g. the ones of the saved puzzle page.
+    pub fn with_examples(mut self, examples: Vec<Example>) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    /// Uses the template in the file `path`.
+    pub fn with_template_file(self, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let template = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(self.with_template(template))
+    }
+
+    /// Uses the template file named by `day_template` in the config, if any.
+    pub fn with_config(self, config: &Config) -> Result<Self> {
+        match config.get("day_template") {
+            Some(path) => self.with_template_file(path),
+            None => Ok(self),
+        }
+    }
+
+    /// The solution of the day, as made from the template.
+    pub fn render(&self) -> String {
+        self.template
+            .replace("{year}", &self.year.to_string())
+            .replace("{day}", &self.day.to_string())
+            .replace("{day:02}", &format!("{:02}", self.day))
+            .replace("{title:?}", &format!("{:?}", self.title))
+            .replace("{title}", &self.title)
+    }
+
+    /// Every file of the day along with its content. The solution goes into
+    /// the crate of the year in `crate_dir` and the inputs to where `locator`
+    /// looks for them first, unless one of its candidates exists already.
+    pub fn files(&self, crate_dir: &Path, locator: &InputLocator) -> Vec<(PathBuf, String)> {
+        let days = crate_dir.join("src").join("days");
+        let inputs = crate_dir.join("inputs");
+        let input = |mode: &ExecutionMode| {
+            let candidates =
+                locator.candidates_in(self.year, self.day, &inputs.to_string_lossy(), mode);
+            let existing = candidates.iter().find(|path| path.exists());
+            existing
+                .or(candidates.first())
+                .expect("there is always at least one candidate")
+                .clone()
+        };
+        let mut files = vec![
+            (days.join(format!("day{}.rs", self.day)), self.render()),
+            (input(&ExecutionMode::Normal), String::new()),
+        ];
+        if self.examples.is_empty() {
+            files.push((input(&ExecutionMode::Test), String::new()));
+        }
+        for example in &self.examples {
+            let path = input(&example.mode);
+            let answers = (answers_path(&path), example.answers.to_string());
+            files.push((path, example.input.clone()));
+            files.push(answers);
+        }
+        files
+    }
+
+    /// Writes the files of the day and returns their paths. Nothing is
+    /// written if any of them already exists.
+    pub fn create(&self, crate_dir: &Path, locator: &InputLocator) -> Result<Vec<PathBuf>> {
+        let files = self.files(crate_dir, locator);
+        if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+            return Err(Error::AlreadyExists { path: path.clone() });
+        }
+
+        let mut created = Vec::new();
+        for (path, content) in files {
+            let io_error = |source| Error::Io {
+                path: path.clone(),
+                source,
+            };
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(io_error)?;
+            }
+            // Something could have appeared since the check above.
+            let mut file = fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+                .map_err(|source| match source.kind() {
+                    ErrorKind::AlreadyExists => Error::AlreadyExists { path: path.clone() },
+                    _ => io_error(source),
+                })?;
+            file.write_all(content.as_bytes()).map_err(io_error)?;
+            created.push(path);
+        }
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzle::examples;
+    use std::env;
+
+    #[test]
+    fn render_template() {
+        let scaffold = Scaffold::new(2025, 3)
+            .with_title("Lobby")
+            .with_template("// {year} day {day:02}: {title}\npub struct Day{day} {}\n");
+        assert_eq!(
+            scaffold.render(),
+            "// 2025 day 03: Lobby\npub struct Day3 {}\n"
+        );
+
+        let quoted = Scaffold::new(2025, 3)
+            .with_title(r#"A "Quoted" \ Title"#)
+            .with_template("{title:?}");
+        assert_eq!(quoted.render(), r#""A \"Quoted\" \\ Title""#);
+
+        let rendered = Scaffold::new(2025, 3).render();
+        assert!(rendered.contains("register_day!(2025, 3, \"Day 3\", Day3);"));
+        assert!(rendered.contains("registry::get(2025, 3)"));
+    }
+
+    #[test]
+    fn example_files() {
+        let page = "<article><pre><code>1 2\n</code></pre><code><em>3</em></code></article>";
+        let scaffold = Scaffold::new(2025, 3).with_examples(examples(page));
+        let files = scaffold.files(Path::new("2025"), &InputLocator::default());
+        let names: Vec<_> = files
+            .iter()
+            .map(|(path, _)| path.to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "2025/src/days/day3.rs",
+                "2025/inputs/day-3.txt",
+                "2025/inputs/day-3-test.txt",
+                "2025/inputs/day-3-test.answers",
+            ]
+        );
+        assert_eq!(files[2].1, "1 2\n");
+        assert_eq!(files[3].1, "1: 3\n");
+
+        let locator = InputLocator::default().with_dir("elsewhere/{year}");
+        let files = Scaffold::new(2025, 3).files(Path::new("2025"), &locator);
+        let names: Vec<_> = files
+            .iter()
+            .map(|(path, _)| path.to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "2025/src/days/day3.rs",
+                "elsewhere/2025/day-3.txt",
+                "elsewhere/2025/day-3-test.txt",
+            ]
+        );
+    }
+
+    #[test]
+    fn create_once() {
+        let dir = env::temp_dir().join(format!("aocutils-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let scaffold = Scaffold::new(2025, 3).with_template("{day}");
+        let locator = InputLocator::default();
+
+        let created = scaffold.create(&dir, &locator).unwrap();
+        assert_eq!(
+            created,
+            [
+                dir.join("src/days/day3.rs"),
+                dir.join("inputs/day-3.txt"),
+                dir.join("inputs/day-3-test.txt"),
+            ]
+        );
+        assert_eq!(fs::read_to_string(&created[0]).unwrap(), "3");
+
+        // Only the example is missing, and it is not created either.
+        fs::remove_file(&created[2]).unwrap();
+        fs::write(&created[0], "solved").unwrap();
+        let error = scaffold
+            .with_template("again")
+            .create(&dir, &locator)
+            .unwrap_err();
+        assert!(matches!(&error, Error::AlreadyExists { path } if *path == created[0]));
+        assert_eq!(fs::read_to_string(&created[0]).unwrap(), "solved");
+        assert!(!created[2].exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{Input, Result};
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Most puzzles ask for a number.
+    Number(i128),
+    /// Some puzzles ask for a word or a code instead.
+    Text(String),
+    /// The part has not been solved (yet).
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Result<Answer> {
+                    Ok(self.into())
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// What a part registered with [`aoc`](crate::aoc) may return: an answer
+/// or anything that becomes one, or a [`Result`] of it.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer>;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Result<Answer> {
+        Ok(self)
+    }
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Result<Answer> {
+        Ok(self.into())
+    }
+}
+
+impl IntoAnswer for &str {
+    fn into_answer(self) -> Result<Answer> {
+        Ok(self.into())
+    }
+}
+
+impl<T: IntoAnswer> IntoAnswer for Result<T> {
+    fn into_answer(self) -> Result<Answer> {
+        self?.into_answer()
+    }
+}
+
+crate::named_enum! {
+    /// One of the two parts of a puzzle.
+    pub enum Part {
+        One => "1",
+        Two => "2",
+    }
+}
+
+/// A solution for a single day of Advent of Code.
+///
+/// The input is parsed once and both parts receive the parsed input and
+/// return their answer instead of printing it, so runners, tests and tooling
+/// can all use it. Input that cannot be parsed is reported as
+/// [`Error::Parse`](crate::Error::Parse).
+pub trait Solution {
+    /// What the input is parsed into. It may borrow from the input.
+    type Parsed<'a>;
+
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>>;
+
+    fn part_one(&self, parsed: &Self::Parsed<'_>) -> Result<Answer>;
+
+    fn part_two(&self, parsed: &Self::Parsed<'_>) -> Result<Answer>;
+}
+
+/// An answer together with the time it took to compute it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedAnswer {
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+/// The answers of one run of a day along with how long each step took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Option<TimedAnswer>,
+    pub part_two: Option<TimedAnswer>,
+}
+
+/// The object safe face of a [`Solution`] which the registry stores.
+pub trait Solver: Sync {
+    /// Parses the input and solves `part`, or both parts if it is `None`.
+    fn solve(&self, input: &Input, part: Option<Part>) -> Result<Timings>;
+}
+
+pub(crate) fn timed<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let start = Instant::now();
+    let result = f()?;
+    Ok((result, start.elapsed()))
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn solve(&self, input: &Input, part: Option<Part>) -> Result<Timings> {
+        let (parsed, parse) = timed(|| self.parse(input))?;
+
+        let solve_part = |selected: Part, solve: &dyn Fn() -> Result<Answer>| {
+            if part.is_some_and(|part| part != selected) {
+                return Ok(None);
+            }
+            let (answer, duration) = timed(solve)?;
+            Ok(Some(TimedAnswer { answer, duration }))
+        };
+
+        Ok(Timings {
+            parse,
+            part_one: solve_part(Part::One, &|| self.part_one(&parsed))?,
+            part_two: solve_part(Part::Two, &|| self.part_two(&parsed))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(42u32).to_string(), "42");
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    ExecutionMode, Input, Result,
+    locate::InputLocator,
+    registry::Day,
+    utility::{read_from, read_input},
+};
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+/// Where the input of a day comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The input file of the day for the mode, wherever the [`InputLocator`] finds it.
+    Mode(ExecutionMode),
+    /// Any file, e.g. a generated or edited input.
+    File(PathBuf),
+    /// Whatever is piped into the program.
+    Stdin,
+    /// The input itself, for quick experiments.
+    Inline(String),
+}
+
+impl InputSource {
+    pub fn load(&self, locator: &InputLocator, day: &Day) -> Result<Input> {
+        match self {
+            InputSource::Mode(mode) => locator.load(day, mode),
+            InputSource::File(path) => read_input(path),
+            InputSource::Stdin => read_from(io::stdin().lock(), Path::new("<stdin>")),
+            InputSource::Inline(text) => Ok(Input::new(text.as_str())),
+        }
+    }
+}
+
+impl From<ExecutionMode> for InputSource {
+    fn from(mode: ExecutionMode) -> Self {
+        InputSource::Mode(mode)
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Mode(mode) => write!(f, "{mode}"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Inline(_) => write!(f, "inline"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::tests::dummy_day;
+
+    const DAY: Day = dummy_day(1, "does/not/exist");
+
+    #[test]
+    fn inline_input() {
+        let source = InputSource::Inline("1abc2\npqr3stu8vwx\n".to_string());
+        let input = source.load(&InputLocator::default(), &DAY).unwrap();
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1abc2", "pqr3stu8vwx"]);
+    }
+
+    #[test]
+    fn file_input() {
+        let path = std::env::temp_dir().join(format!("aocutils-source-{}.txt", std::process::id()));
+        std::fs::write(&path, "a\nb\nc\n").unwrap();
+        let input = InputSource::File(path.clone()).load(&InputLocator::default(), &DAY);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(input.unwrap().raw(), "a\nb\nc\n");
+    }
+}
@@ -0,0 +1,363 @@
+use crate::{
+    Error, ExecutionMode, Input, Part, Result,
+    locate::InputLocator,
+    parse::Line,
+    puzzle::{articles, text},
+    registry::Day,
+};
+use std::{
+    fmt, fs,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+crate::named_enum! {
+    /// What the website said about a submitted answer.
+    pub enum Outcome {
+        Correct => "correct",
+        Wrong => "wrong",
+        TooHigh => "too-high",
+        TooLow => "too-low",
+        /// Another answer was submitted too recently, so this one was not checked.
+        RateLimited => "rate-limited",
+    }
+}
+
+/// The reply to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub outcome: Outcome,
+    /// What the website wrote, e.g. how long to wait before the next try.
+    pub message: String,
+}
+
+/// The text of the main part of a page, without its markup.
+pub fn reply_text(html: &str) -> String {
+    text(articles(html)[0])
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Recognizes the reply to a submitted answer, if it is one of the known ones.
+pub fn parse_reply(html: &str) -> Option<Reply> {
+    let message = reply_text(html);
+    let outcome = if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("answer too recently") {
+        Outcome::RateLimited
+    } else if !message.contains("not the right answer") {
+        return None;
+    } else if message.contains("too high") {
+        Outcome::TooHigh
+    } else if message.contains("too low") {
+        Outcome::TooLow
+    } else {
+        Outcome::Wrong
+    };
+    Some(Reply { outcome, message })
+}
+
+/// One submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+/// Whether an answer is worth submitting, judging by the earlier ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing speaks against submitting it.
+    Submit,
+    /// The same answer was submitted before.
+    Known(Outcome),
+    /// The part was solved with this other answer.
+    Solved(String),
+    /// The answer is no smaller than this one, which was too high.
+    AboveBound(i128),
+    /// The answer is no larger than this one, which was too low.
+    BelowBound(i128),
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Submit => write!(f, "it has not been submitted yet"),
+            Check::Known(outcome) => write!(f, "it was submitted before and was {outcome}"),
+            Check::Solved(answer) => write!(f, "the part was solved with {answer}"),
+            Check::AboveBound(bound) => write!(f, "{bound} was already too high"),
+            Check::BelowBound(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+/// The answers submitted for the days of one year along with their outcomes.
+///
+/// It is stored as one `<day> <part> <outcome> <answer>` line per answer,
+/// next to where the inputs of the year are downloaded to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Where the ledger of the year of `day` is kept.
+    pub fn path(locator: &InputLocator, day: &Day) -> PathBuf {
+        let input = locator
+            .candidates(day, &ExecutionMode::Normal)
+            .into_iter()
+            .next()
+            .expect("there is always at least one candidate");
+        let dir = input.parent().unwrap_or(Path::new(""));
+        dir.join(format!("submissions-{}.txt", day.year))
+    }
+
+    /// Loads the ledger at `path`, which is empty if there is no file yet.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let entries = match fs::read_to_string(&path) {
+            Ok(text) => Input::new(text)
+                .parse_lines(parse_entry)
+                .map_err(|error| match error {
+                    Error::Parse { line, message, .. } => Error::InvalidLedger {
+                        path: path.clone(),
+                        line,
+                        message,
+                    },
+                    error => error,
+                })?,
+            Err(error) if error.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(source) => return Err(Error::Io { path, source }),
+        };
+        Ok(Ledger { path, entries })
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Checks `answer` against the earlier answers for the same part.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Check {
+        let earlier = || {
+            self.entries
+                .iter()
+                .filter(move |entry| entry.day == day && entry.part == part)
+        };
+        if let Some(solved) = earlier().find(|entry| entry.outcome == Outcome::Correct) {
+            return match solved.answer == answer {
+                true => Check::Known(Outcome::Correct),
+                false => Check::Solved(solved.answer.clone()),
+            };
+        }
+        if let Some(entry) = earlier().find(|entry| entry.answer == answer) {
+            return Check::Known(entry.outcome);
+        }
+
+        let Ok(number) = answer.parse::<i128>() else {
+            return Check::Submit;
+        };
+        let bound = |outcome| {
+            earlier()
+                .filter(move |entry| entry.outcome == outcome)
+                .filter_map(|entry| entry.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Outcome::TooHigh).min().filter(|high| number >= *high) {
+            return Check::AboveBound(high);
+        }
+        if let Some(low) = bound(Outcome::TooLow).max().filter(|low| number <= *low) {
+            return Check::BelowBound(low);
+        }
+        Check::Submit
+    }
+
+    /// Adds `entry` to the ledger and its file. Answers that were not checked
+    /// because of the rate limit are not worth remembering.
+    ///
+    /// An answer takes the rest of its line, so it may contain spaces but
+    /// not line breaks or whitespace at either end.
+    pub fn record(&mut self, entry: Entry) -> Result<()> {
+        if entry.outcome == Outcome::RateLimited {
+            return Ok(());
+        }
+        if entry.answer.is_empty()
+            || entry.answer.trim() != entry.answer
+            || entry.answer.contains('\n')
+        {
+            return Err(Error::InvalidLedger {
+                path: self.path.clone(),
+                line: self.entries.len() + 1,
+                message: format!("the answer {:?} does not fit on one line", entry.answer),
+            });
+        }
+        let io_error = |source| Error::Io {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(
+            file,
+            "{} {} {} {}",
+            entry.day, entry.part, entry.outcome, entry.answer
+        )
+        .map_err(io_error)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+}
+
+fn parse_entry(line: Line) -> Result<Entry> {
+    let mut fields = line.fields();
+    Ok(Entry {
+        day: fields.parse_next()?,
+        part: fields.parse_next()?,
+        outcome: fields.parse_next()?,
+        answer: fields.rest()?.as_str().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "client")]
+    use crate::client::{Client, stand_in::serve};
+    use std::env;
+
+    const TOO_HIGH: &str = "<html><main><article><p>That's not the right answer; \
+        your answer is too high.  If you're stuck, make sure you're using the full \
+        input data. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article></main></html>";
+
+    #[test]
+    fn replies() {
+        let reply = parse_reply(TOO_HIGH).unwrap();
+        assert_eq!(reply.outcome, Outcome::TooHigh);
+        assert!(
+            reply
+                .message
+                .starts_with("That's not the right answer; your answer")
+        );
+        assert!(reply.message.ends_with("[Return to Day 5]"));
+
+        let outcome = |text: &str| parse_reply(&format!("<article><p>{text}</p></article>"));
+        assert_eq!(
+            outcome("That's the right answer! You are one gold star closer.")
+                .unwrap()
+                .outcome,
+            Outcome::Correct
+        );
+        assert_eq!(
+            outcome("That's not the right answer. Please wait one minute.")
+                .unwrap()
+                .outcome,
+            Outcome::Wrong
+        );
+        assert_eq!(
+            outcome("That's not the right answer; your answer is too low.")
+                .unwrap()
+                .outcome,
+            Outcome::TooLow
+        );
+        let limited = outcome("You gave an answer too recently. You have 42s left to wait.");
+        assert_eq!(limited.unwrap().outcome, Outcome::RateLimited);
+        assert_eq!(
+            outcome("You don't seem to be solving the right level."),
+            None
+        );
+    }
+
+    fn entry(part: Part, outcome: Outcome, answer: &str) -> Entry {
+        Entry {
+            day: 5,
+            part,
+            outcome,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn ledger_checks() {
+        let path = env::temp_dir().join(format!("aocutils-ledger-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut ledger = Ledger::load(&path).unwrap();
+        assert_eq!(ledger.check(5, Part::One, "50"), Check::Submit);
+
+        for entry in [
+            entry(Part::One, Outcome::TooHigh, "100"),
+            entry(Part::One, Outcome::TooLow, "10"),
+            entry(Part::One, Outcome::Wrong, "a b c"),
+            entry(Part::One, Outcome::RateLimited, "50"),
+            entry(Part::Two, Outcome::Correct, "7"),
+        ] {
+            ledger.record(entry).unwrap();
+        }
+        assert!(matches!(
+            ledger.record(entry(Part::One, Outcome::Wrong, "a\nb")),
+            Err(Error::InvalidLedger { line: 5, .. })
+        ));
+        let ledger = Ledger::load(&path).unwrap();
+        assert_eq!(ledger.entries().len(), 4);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap().lines().next(),
+            Some("5 1 too-high 100")
+        );
+
+        assert_eq!(ledger.check(5, Part::One, "50"), Check::Submit);
+        assert_eq!(
+            ledger.check(5, Part::One, "100"),
+            Check::Known(Outcome::TooHigh)
+        );
+        assert_eq!(
+            ledger.check(5, Part::One, "a b c"),
+            Check::Known(Outcome::Wrong)
+        );
+        assert_eq!(ledger.check(5, Part::One, "150"), Check::AboveBound(100));
+        assert_eq!(ledger.check(5, Part::One, "-3"), Check::BelowBound(10));
+        assert_eq!(
+            ledger.check(5, Part::Two, "7"),
+            Check::Known(Outcome::Correct)
+        );
+        assert_eq!(
+            ledger.check(5, Part::Two, "8"),
+            Check::Solved("7".to_string())
+        );
+        assert_eq!(ledger.check(6, Part::One, "150"), Check::Submit);
+
+        fs::write(&path, "5 1 maybe 3\n").unwrap();
+        assert!(matches!(
+            Ledger::load(&path),
+            Err(Error::InvalidLedger { line: 1, .. })
+        ));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "client")]
+    fn submit_answer() {
+        let (url, server) = serve(1, "200 OK", TOO_HIGH);
+        let mut client = Client::new("abc").with_base_url(url);
+        let reply = client.submit(2023, 5, Part::Two, "123").unwrap();
+        assert_eq!(reply.outcome, Outcome::TooHigh);
+        assert_eq!(
+            server.join().unwrap(),
+            ["POST /2023/day/5/answer HTTP/1.1\nCookie: session=abc\nlevel=2&answer=123"]
+        );
+
+        let (url, server) = serve(1, "200 OK", "<article>Who are you?</article>");
+        let mut client = Client::new("abc").with_base_url(url);
+        let error = client.submit(2023, 5, Part::One, "1").unwrap_err();
+        server.join().unwrap();
+        assert!(
+            matches!(error, Error::Http { message, .. } if message == "unexpected reply: Who are you?")
+        );
+    }
+}
@@ -1,48 +1,93 @@
-use crate::ExecutionMode;
+use crate::{Error, ExecutionMode, Input, Result, source::InputSource};
 use std::{
     fs::File,
-    io::{self, BufRead},
+    io::{self, Read},
     path::Path,
-    process::exit,
 };
 
-pub fn read_input<P>(file_name: P) -> Result<Vec<String>, String>
+pub fn read_input<P>(file_name: P) -> Result<Input>
 where
     P: AsRef<Path>,
 {
-    let file = File::open(&file_name);
-    match file {
-        Ok(file) => {
-            let mut lines: Vec<String> = Vec::new();
-            for line in io::BufReader::new(file).lines() {
-                match line {
-                    Ok(line) => lines.push(line),
-                    Err(err) => {
-                        return Err(format!(
-                            "Unable to read line from file {:?}: {}",
-                            file_name.as_ref().to_str(),
-                            err
-                        ))
-                    }
-                }
-            }
-            Ok(lines)
+    let path = file_name.as_ref();
+    let file = File::open(path).map_err(|source| Error::MissingInput {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    read_from(file, path)
+}
+
+/// Reads everything from `reader`, naming it `path` in errors.
+pub fn read_from(mut reader: impl Read, path: &Path) -> Result<Input> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    match String::from_utf8(bytes) {
+        Ok(text) => Ok(Input::new(text)),
+        Err(error) => {
+            let valid = &error.as_bytes()[..error.utf8_error().valid_up_to()];
+            Err(Error::UnreadableLine {
+                path: path.to_path_buf(),
+                line: valid.iter().filter(|byte| **byte == b'\n').count() + 1,
+                source: io::Error::new(io::ErrorKind::InvalidData, error.utf8_error()),
+            })
         }
-        Err(err) => Err(format!(
-            "Unable to open file {:?}: {}",
-            file_name.as_ref().to_str(),
-            err
-        )),
     }
 }
 
-pub fn parse_execution_mode(mode: String) -> ExecutionMode {
-    match mode.as_str() {
-        "normal" => ExecutionMode::Normal,
-        "test" => ExecutionMode::Test,
-        _ => {
-            println!("Wrong execution mode.");
-            exit(-1);
-        }
+pub fn parse_execution_mode(mode: &str) -> Result<ExecutionMode> {
+    match mode {
+        "normal" => Ok(ExecutionMode::Normal),
+        "test" => Ok(ExecutionMode::Test),
+        _ => match mode.strip_prefix("test-") {
+            Some(name) if !name.is_empty() => Ok(ExecutionMode::Example(name.to_string())),
+            _ => Err(Error::UnknownMode(mode.to_string())),
+        },
+    }
+}
+
+/// `-` is stdin, anything else a path.
+pub fn parse_input_source(source: &str) -> Result<InputSource> {
+    match source {
+        "-" => Ok(InputSource::Stdin),
+        path => Ok(InputSource::File(path.into())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_input() {
+        let error = read_input("does/not/exist.txt").unwrap_err();
+        assert!(matches!(error, Error::MissingInput { .. }));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let error = read_from(&b"abc\nd\xffe\n"[..], Path::new("input.txt")).unwrap_err();
+        assert!(matches!(error, Error::UnreadableLine { line: 2, .. }));
+    }
+
+    #[test]
+    fn example_mode() {
+        assert_eq!(
+            parse_execution_mode("test-2").unwrap(),
+            ExecutionMode::Example("2".to_string())
+        );
+        assert!(parse_execution_mode("test-").is_err());
+    }
+
+    #[test]
+    fn unknown_mode() {
+        assert!(matches!(
+            parse_execution_mode("fast"),
+            Err(Error::UnknownMode(mode)) if mode == "fast"
+        ));
     }
 }
@@ -0,0 +1,46 @@
+use aocutils::{Answer, Input, Result, Solution, register_day};
+
+pub struct Day{day};
+
+register_day!({year}, {day}, {title:?}, Day{day});
+
+impl Solution for Day{day} {
+    type Parsed<'a> = &'a Input;
+
+    fn parse<'a>(&self, input: &'a Input) -> Result<Self::Parsed<'a>> {
+        Ok(input)
+    }
+
+    fn part_one(&self, _input: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part_two(&self, _input: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aocutils::{
+        Answer,
+        answers::{Verdict, verify_day},
+        locate::InputLocator,
+        registry,
+    };
+
+    #[test]
+    fn examples() {
+        let locator = InputLocator::from_env().unwrap();
+        let day = registry::get({year}, {day}).unwrap();
+        for mode in locator.examples(day) {
+            for verdict in verify_day(&locator, day, &mode).unwrap() {
+                // Parts that are not solved yet have nothing to check.
+                assert!(
+                    !matches!(&verdict, Verdict::Fail { actual, .. } if *actual != Answer::Unsolved),
+                    "{mode}: {verdict:?}"
+                );
+            }
+        }
+    }
+}